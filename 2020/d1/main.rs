@@ -1,18 +1,11 @@
-use std::time::Instant;
-
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day1>();
 }
 
+aoclib::solution!(Day1, 2020, 1);
+
 fn part1(txt: &str) -> i32 {
     let target = 2020;
     let expenses = txt
@@ -62,7 +55,7 @@ fn find_pair(target: i32, within: &Vec<i32>) -> Option<i32> {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn sample_input_pt1() {
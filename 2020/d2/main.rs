@@ -1,18 +1,13 @@
-use std::{str::FromStr, time::Instant};
+use std::str::FromStr;
 
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day2>();
 }
 
+aoclib::solution!(Day2, 2020, 2);
+
 fn part1(txt: &str) -> usize {
     count(txt, Part1)
 }
@@ -101,7 +96,8 @@ impl FromStr for PasswordPolicy {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_parse() {
@@ -142,8 +138,7 @@ mod tests {
 
     #[test]
     fn input_pt1_answer() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(416, part1(test_input));
+        aoclib::answers::assert_correct::<Day2>(Part::One);
     }
 
     #[test]
@@ -155,13 +150,12 @@ mod tests {
             .map(|l| l.parse::<PasswordPolicy>().expect("password policy"))
             .filter(|p| (&validator).valid(p))
             .collect_vec();
-        println!("{:?}", result);
+        eprintln!("{:?}", result);
         assert_eq!(1, result.len());
     }
 
     #[test]
     fn input_pt2_answer() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(688, part2(test_input));
+        aoclib::answers::assert_correct::<Day2>(Part::Two);
     }
 }
@@ -1,21 +1,13 @@
-use std::{
-    fmt::{Display, Write},
-    time::Instant,
-};
+use std::fmt::{Display, Write};
 
 use aoclib::grid::{FromChar, Grid, GridPosition};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day3>();
 }
 
+aoclib::solution!(Day3, 2020, 3);
+
 fn part1(txt: &str) -> usize {
     let g: Grid<Square> = txt.parse().expect("grid");
     sled(1, 3, &g)
@@ -33,24 +25,15 @@ fn part2(txt: &str) -> usize {
 }
 
 fn sled(row_delta: usize, col_delta: usize, g: &Grid<Square>) -> usize {
-    let mut row: usize = 0;
-    let mut col: usize = 0;
-    let mut trees_hit = 0;
-    loop {
-        row += row_delta;
-        col += col_delta;
-
-        col = col % g.width();
-
-        if row >= g.height() {
-            break;
-        }
-
-        if g.at(&GridPosition::new(col, row)) == &Square::Tree {
-            trees_hit += 1;
-        }
-    }
-    trees_hit
+    // the slope repeats to the right, but the run ends at the bottom
+    let steps = (g.height() - 1) / row_delta;
+    g.ray_wrapping(
+        &GridPosition::new(0, 0),
+        (col_delta as isize, row_delta as isize),
+    )
+    .take(steps)
+    .filter(|(_, square)| **square == Square::Tree)
+    .count()
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -82,7 +65,8 @@ impl Display for Square {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_parsing() {
@@ -104,8 +88,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(189, part1(test_input));
+        aoclib::answers::assert_correct::<Day3>(Part::One);
     }
 
     #[test]
@@ -115,7 +98,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1718180100, part2(test_input));
+        aoclib::answers::assert_correct::<Day3>(Part::Two);
     }
 }
@@ -1,23 +1,17 @@
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
-    time::Instant,
 };
 
 use aoclib::input::{NEW_LINE, empty_line_chunks};
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day4>();
 }
 
+aoclib::solution!(Day4, 2020, 4);
+
 fn part1(txt: &str) -> usize {
     empty_line_chunks(txt)
         .filter(|chunk| !chunk.is_empty())
@@ -239,7 +233,8 @@ impl Passport {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn parse_length() {
@@ -292,8 +287,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(204, part1(test_input));
+        aoclib::answers::assert_correct::<Day4>(Part::One);
     }
 
     #[test]
@@ -321,7 +315,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(179, part2(test_input));
+        aoclib::answers::assert_correct::<Day4>(Part::Two);
     }
 }
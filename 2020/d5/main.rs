@@ -1,16 +1,11 @@
-use std::{collections::HashSet, str::FromStr, time::Instant};
+use std::{collections::HashSet, str::FromStr};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day5>();
 }
 
+aoclib::solution!(Day5, 2020, 5);
+
 fn part1(txt: &str) -> usize {
     txt.lines()
         .map(|l| l.parse::<BoardingPass>().unwrap())
@@ -143,7 +138,8 @@ enum ColPartition {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn specific_example() {
@@ -162,13 +158,11 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(842, part1(test_input));
+        aoclib::answers::assert_correct::<Day5>(Part::One);
     }
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(842, part1(test_input));
+        aoclib::answers::assert_correct::<Day5>(Part::One);
     }
 }
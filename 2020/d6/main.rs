@@ -1,18 +1,13 @@
-use std::{collections::HashSet, fmt::Debug, str::FromStr, time::Instant};
+use std::{collections::HashSet, fmt::Debug, str::FromStr};
 
 use aoclib::input;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day6>();
 }
 
+aoclib::solution!(Day6, 2020, 6);
+
 fn part1(txt: &str) -> usize {
     count_yes::<AnyYes>(txt)
 }
@@ -88,7 +83,8 @@ impl FromStr for AllYes {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn sample_input_pt1() {
@@ -98,8 +94,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(6748, part1(test_input));
+        aoclib::answers::assert_correct::<Day6>(Part::One);
     }
 
     #[test]
@@ -110,7 +105,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(3445, part2(test_input));
+        aoclib::answers::assert_correct::<Day6>(Part::Two);
     }
 }
@@ -0,0 +1,32 @@
+//! Every 2020 day as a module so the `aoc` runner can solve them in-process.
+//! Each day's `main` is only used by its own binary.
+#![allow(dead_code)]
+
+use aoclib::solution::Runnable;
+
+#[path = "d1/main.rs"]
+pub mod d1;
+
+#[path = "d2/main.rs"]
+pub mod d2;
+
+#[path = "d3/main.rs"]
+pub mod d3;
+
+#[path = "d4/main.rs"]
+pub mod d4;
+
+#[path = "d5/main.rs"]
+pub mod d5;
+
+#[path = "d6/main.rs"]
+pub mod d6;
+
+pub static SOLUTIONS: &[&dyn Runnable] = &[
+    &d1::Day1,
+    &d2::Day2,
+    &d3::Day3,
+    &d4::Day4,
+    &d5::Day5,
+    &d6::Day6,
+];
@@ -1,12 +1,22 @@
 use std::collections::HashMap;
 
 fn main() {
-    part1();
+    aoclib::solution::main::<Day10>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
+aoclib::solution!(Day10, 2021, 10);
 
+fn part1(input: &str) -> i64 {
+    check_lines(input).0
+}
+
+fn part2(input: &str) -> i64 {
+    complete(&check_lines(input).1)
+}
+
+/// The syntax error score of the corrupted lines, and what's left open on the
+/// incomplete ones
+fn check_lines(input: &str) -> (i64, Vec<Vec<char>>) {
     let opens = brackets();
     let points = part1_points();
 
@@ -37,8 +47,7 @@ fn part1() {
         }
     }
 
-    println!("part 1: {}", total_points);
-    part2(&incomplete);
+    (total_points, incomplete)
 }
 
 fn brackets() -> HashMap<char, char> {
@@ -59,7 +68,7 @@ fn part2_points() -> HashMap<char, i64> {
         .collect()
 }
 
-fn part2(incomplete: &Vec<Vec<char>>) {
+fn complete(incomplete: &Vec<Vec<char>>) -> i64 {
     let pt2_points = part2_points();
     let mut part2: Vec<i64> = Vec::new();
     for it in incomplete {
@@ -73,5 +82,12 @@ fn part2(incomplete: &Vec<Vec<char>>) {
     }
 
     part2.sort();
-    println!("part 2: {:?}", part2[part2.len() / 2]);
+    part2[part2.len() / 2]
+}
+
+#[test]
+fn example() {
+    let input = include_str!("input.test.txt");
+    assert_eq!(26397, part1(input));
+    assert_eq!(288957, part2(input));
 }
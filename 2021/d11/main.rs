@@ -1,30 +1,25 @@
 use std::{collections::HashSet, fmt::Debug, str::FromStr};
 
 fn main() {
-    part1();
-    part2();
+    aoclib::solution::main::<Day11>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
+aoclib::solution!(Day11, 2021, 11);
+
+fn part1(input: &str) -> usize {
     let mut grid: Grid = input.parse().unwrap();
 
     let steps = 100;
     let mut flash_count = 0;
 
-    for step in 0..steps {
+    for _ in 0..steps {
         let flashes = grid.step();
         flash_count += flashes.len();
-
-        println!("Step {}", step + 1);
-        println!("{:?}", grid);
-        println!("");
     }
-    println!("part1: {}", flash_count);
+    flash_count
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
+fn part2(input: &str) -> usize {
     let mut grid: Grid = input.parse().unwrap();
 
     let mut step = 0;
@@ -34,8 +29,7 @@ fn part2() {
         step += 1;
         let num_flashed = flashed.len();
         if num_flashed == 100 {
-            println!("part2: {}", step);
-            break;
+            return step;
         }
     }
 }
@@ -78,9 +72,10 @@ impl FromStr for Grid {
         let mut grid: Vec<Vec<i32>> = Vec::new();
 
         for (i, line) in s.lines().enumerate() {
+            grid.push(Vec::new());
             for (j, c) in line.chars().enumerate() {
                 if let Some(value) = c.to_digit(RADIX) {
-                    grid[i][j] = value as i32;
+                    grid[i].push(value as i32);
                 } else {
                     return Err(GridParseErr {
                         line: i,
@@ -147,3 +142,10 @@ impl Grid {
         flashed
     }
 }
+
+#[test]
+fn example() {
+    let input = include_str!("input.test.txt");
+    assert_eq!(1656, part1(input));
+    assert_eq!(195, part2(input));
+}
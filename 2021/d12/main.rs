@@ -2,23 +2,19 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 
 fn main() {
-    part1();
-    part2();
+    aoclib::solution::main::<Day12>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
-    let connections = parse(input);
+aoclib::solution!(Day12, 2021, 12);
 
-    let result = count("start".to_string(), &connections, &mut Vec::new());
-    println!("part1: {:?}", result);
+fn part1(input: &str) -> i32 {
+    let connections = parse(input);
+    count("start".to_string(), &connections, &mut Vec::new())
 }
 
-fn part2() {
-    pt2(include_str!("simple.test.txt"));
-    pt2(include_str!("slightly_larger.test.txt"));
-    pt2(include_str!("input.test.txt"));
-    pt2(include_str!("input.txt"));
+fn part2(input: &str) -> i32 {
+    let connections = parse(input);
+    count2("start".to_string(), &connections, &mut Vec::new())
 }
 
 fn parse(input: &str) -> HashMap<String, Vec<String>> {
@@ -74,13 +70,6 @@ fn count(curr: String, mappings: &HashMap<String, Vec<String>>, path: &mut Vec<S
     }
 }
 
-fn pt2(input: &str) {
-    let connections = parse(input);
-
-    let result = count2("start".to_string(), &connections, &mut Vec::new());
-    println!("part2: {:?}", result);
-}
-
 fn count2(curr: String, mappings: &HashMap<String, Vec<String>>, smalls: &mut Vec<String>) -> i32 {
     let is_lower = curr.to_lowercase() == curr;
 
@@ -107,3 +96,11 @@ fn count2(curr: String, mappings: &HashMap<String, Vec<String>>, smalls: &mut Ve
         }
     }
 }
+
+#[test]
+fn examples() {
+    assert_eq!(10, part1(include_str!("simple.test.txt")));
+    assert_eq!(36, part2(include_str!("simple.test.txt")));
+    assert_eq!(103, part2(include_str!("slightly_larger.test.txt")));
+    assert_eq!(3509, part2(include_str!("input.test.txt")));
+}
@@ -1,52 +1,60 @@
 use std::{collections::HashSet, str::FromStr};
 
+use aoclib::{Answer, cartesian::Point, ocr};
+
 fn main() {
-    part1();
-    part2();
+    aoclib::solution::main::<Day13>();
 }
 
-fn part1() {
-    let input = include_str!("input.test.txt");
+aoclib::solution!(Day13, 2021, 13);
 
+fn part1(input: &str) -> usize {
     let mut positions: HashSet<(i64, i64)> = HashSet::new();
     let mut folds: Vec<Fold> = Vec::new();
 
     parse(input, &mut positions, &mut folds);
 
-    print_grid(&positions);
-
-    let result = folds
+    folds
         .iter()
         .take(1)
-        .fold(positions.clone(), |accum, item| fold(accum, item));
+        .fold(positions, |accum, item| fold(accum, item))
+        .len()
+}
+
+/// The letters the dots spell, or the dots themselves when they can't be read
+fn part2(input: &str) -> Answer {
+    let mut positions: HashSet<(i64, i64)> = HashSet::new();
+    let mut folds: Vec<Fold> = Vec::new();
 
-    print_grid(&result);
+    parse(input, &mut positions, &mut folds);
 
-    let result2 = folds
+    let result = folds
         .iter()
-        .take(2)
-        .fold(positions.clone(), |accum, item| fold(accum, item));
-
-    print_grid(&result2);
-}
+        .fold(positions, |accum, item| fold(accum, item));
 
-fn print_grid(result: &HashSet<(i64, i64)>) {
-    let max_x = result.iter().map(|(x, _y)| *x).max().unwrap();
-    let max_y = result.iter().map(|(_x, y)| *y).max().unwrap();
-
-    println!("");
-    for y in 0..max_y + 1 {
-        for x in 0..max_x + 1 {
-            let pos = (x, y);
-            if result.contains(&pos) {
-                print!("#")
-            } else {
-                print!(" ")
-            }
+    let dots: Vec<Point> = result.iter().copied().map(Point::from).collect();
+    match ocr::read_points(&dots) {
+        Ok(letters) => Answer::Text(letters),
+        Err(e) => {
+            eprintln!("{e}");
+            draw(&result)
         }
-        println!("")
     }
-    println!("");
+}
+
+fn draw(result: &HashSet<(i64, i64)>) -> Answer {
+    let max_x = result.iter().map(|(x, _y)| *x).max().unwrap_or(0);
+    let max_y = result.iter().map(|(_x, y)| *y).max().unwrap_or(0);
+
+    Answer::Grid(
+        (0..max_y + 1)
+            .map(|y| {
+                (0..max_x + 1)
+                    .map(|x| if result.contains(&(x, y)) { '#' } else { ' ' })
+                    .collect()
+            })
+            .collect(),
+    )
 }
 
 fn fold(positions: HashSet<(i64, i64)>, fold: &Fold) -> HashSet<(i64, i64)> {
@@ -122,22 +130,22 @@ fn test_fold_y() {
     let input = HashSet::from_iter(vec![(0, 4), (1, 5), (2, 6)]);
     let result = fold_y(input, 3);
 
-    println!("fold y=3 -> {:?}", result);
+    assert_eq!(HashSet::from_iter(vec![(0, 2), (1, 1), (2, 0)]), result);
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
-
-    let mut positions: HashSet<(i64, i64)> = HashSet::new();
-    let mut folds: Vec<Fold> = Vec::new();
-
-    parse(input, &mut positions, &mut folds);
-
-    let result = folds
-        .iter()
-        .fold(positions, |accum, item| fold(accum, item));
-
-    print_grid(&result);
+#[test]
+fn example() {
+    let input = include_str!("input.test.txt");
+    assert_eq!(17, part1(input));
+    // a square, which isn't a letter
+    assert_eq!(
+        Answer::Grid(
+            ["#####", "#   #", "#   #", "#   #", "#####"]
+                .map(String::from)
+                .into()
+        ),
+        part2(input)
+    );
 }
 
 fn parse(input: &str, positions: &mut HashSet<(i64, i64)>, folds: &mut Vec<Fold>) {
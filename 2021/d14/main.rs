@@ -4,13 +4,12 @@ use std::{
 };
 
 fn main() {
-    part1();
-    part2();
+    aoclib::solution::main::<Day14>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
+aoclib::solution!(Day14, 2021, 14);
 
+fn part1(input: &str) -> i64 {
     let chain = input.lines().nth(0).unwrap();
     let mut insertions: HashMap<[char; 2], Insertion> = HashMap::new();
 
@@ -43,8 +42,7 @@ fn part1() {
         .min_by(|left, right| left.1.cmp(right.1))
         .unwrap();
 
-    println!("after n {}: len: {:?}", n, input.len());
-    println!("pt1: {}", max_count - min_count);
+    max_count - min_count
 }
 
 fn step(input: &Vec<char>, insertions: &HashMap<[char; 2], Insertion>) -> Vec<char> {
@@ -89,9 +87,7 @@ impl FromStr for Insertion {
     }
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
-
+fn part2(input: &str) -> i64 {
     let chain = input.lines().nth(0).unwrap();
     let mut insertions: HashMap<[char; 2], Insertion> = HashMap::new();
 
@@ -137,24 +133,9 @@ fn part2() {
         counts.insert(c, sum);
     }
 
-    let (max, max_count) = counts
-        .iter()
-        .max_by(|left, right| left.1.cmp(right.1))
-        .unwrap();
-
-    let (min, min_count) = counts
-        .iter()
-        .min_by(|left, right| left.1.cmp(right.1))
-        .unwrap();
-
-    println!(
-        "pt2: {} ({} -> {}, {} -> {})",
-        max_count - min_count,
-        min,
-        min_count,
-        max,
-        max_count
-    );
+    let max_count = counts.values().max().unwrap();
+    let min_count = counts.values().min().unwrap();
+    max_count - min_count
 }
 
 fn step2(
@@ -183,3 +164,10 @@ fn step2(
 
     result
 }
+
+#[test]
+fn example() {
+    let input = include_str!("input.test.txt");
+    assert_eq!(1588, part1(input));
+    assert_eq!(2188189693529, part2(input));
+}
@@ -1,36 +1,30 @@
 use std::collections::BTreeSet;
 
-use std::time::Instant;
 use std::{collections::HashMap, fmt::Debug, str::FromStr};
 
 /// Use sufficiently high number that a real hueristic wouldn't be above
 const INFINITY: i64 = 1000000;
 
 fn main() {
-    let start = Instant::now();
-    part1();
-    part2();
-    println!("took: {}ms", start.elapsed().as_millis())
+    aoclib::solution::main::<Day15>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
+aoclib::solution!(Day15, 2021, 15);
+
+fn part1(input: &str) -> i64 {
     let grid: Grid = input.parse().unwrap();
     let height: i64 = grid.1 as i64;
     let width: i64 = grid.2 as i64;
-    let result = astar(&grid, (0, 0), (height - 1, width - 1));
-    println!("result: {}", result);
+    astar(&grid, (0, 0), (height - 1, width - 1))
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
+fn part2(input: &str) -> i64 {
     let mut grid: Grid = input.parse().unwrap();
     grid = expand(&grid, 5);
     let height: i64 = grid.1 as i64;
     let width: i64 = grid.2 as i64;
 
-    let result = astar(&grid, (0, 0), (height - 1, width - 1));
-    println!("result: {}", result);
+    astar(&grid, (0, 0), (height - 1, width - 1))
 }
 
 #[derive(Clone, Debug)]
@@ -176,10 +170,6 @@ fn expand(grid: &Grid, times: usize) -> Grid {
     let new_rows = grid.1 * times;
     let new_cols = grid.2 * times;
     let mut result: Vec<Vec<i64>> = Vec::with_capacity(new_rows);
-    println!(
-        "expanding grid to h:{} w:{} from {},{}",
-        new_rows, new_cols, grid.1, grid.2
-    );
 
     for row in 0..new_rows {
         let mut row_vals: Vec<i64> = Vec::with_capacity(new_cols);
@@ -221,3 +211,10 @@ fn test_expand_example() {
 
     assert_eq!(expand(&grid, 5).0, expanded_grid.0);
 }
+
+#[test]
+fn example() {
+    let input = include_str!("input.test.txt");
+    assert_eq!(40, part1(input));
+    assert_eq!(315, part2(input));
+}
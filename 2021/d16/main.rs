@@ -1,27 +1,22 @@
-use std::time::Instant;
-
 fn main() {
-    let start = Instant::now();
-    part1();
-    part2();
-    println!("took: {} μs", start.elapsed().as_micros())
+    aoclib::solution::main::<Day16>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
-    let input_as_bin = hex_to_bin(input);
+aoclib::solution!(Day16, 2021, 16);
+
+fn part1(input: &str) -> i64 {
+    let input_as_bin = hex_to_bin(input.trim());
     let result = parse(&input_as_bin);
-    println!("part1: {:?}", sum(&result));
+    sum(&result)
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
-    let input_as_bin = hex_to_bin(input);
+fn part2(input: &str) -> i64 {
+    let input_as_bin = hex_to_bin(input.trim());
     let result = parse(&input_as_bin);
     if result.len() > 1 {
         panic!("size was {}", result.len());
     }
-    println!("part2: {:?}", result[0].calc());
+    result[0].calc()
 }
 
 #[derive(Debug)]
@@ -1,15 +1,12 @@
-use std::{collections::HashSet, str::FromStr, time::Instant};
+use std::{collections::HashSet, str::FromStr};
 
 fn main() {
-    let start = Instant::now();
-    part1();
-    part2();
-    println!("took: {} μs", start.elapsed().as_micros())
+    aoclib::solution::main::<Day17>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
-    let target_area = input.parse::<Box>().unwrap();
+aoclib::solution!(Day17, 2021, 17);
+
+fn part1(input: &str) -> i64 {    let target_area = input.trim().parse::<Box>().unwrap();
 
     let mut max_y = 0;
     let mut end_y = 0;
@@ -34,12 +31,10 @@ fn part1() {
         }
         max_y = i64::max(max_y, max_y_in_run);
     }
-    println!("part1: {}", max_y);
+    max_y
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
-    let target_area = input.parse::<Box>().unwrap();
+fn part2(input: &str) -> usize {    let target_area = input.trim().parse::<Box>().unwrap();
     let mut matches: HashSet<(i64, i64)> = HashSet::new();
 
     for n in 0..1000 {
@@ -56,7 +51,7 @@ fn part2() {
         }
     }
 
-    println!("part2: {}", matches.len());
+    matches.len()
 }
 
 fn calc_y(n: i64, v: i64) -> i64 {
@@ -206,7 +201,7 @@ fn test_box_contains() {
 #[test]
 fn test_parse_box() {
     let input = "target area: x=20..30, y=-10..-5";
-    let b = input.parse::<Box>();
+    let b = input.trim().parse::<Box>();
 
     assert_eq!(
         Ok(Box {
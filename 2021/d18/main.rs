@@ -1,15 +1,12 @@
-use std::{str::FromStr, time::Instant};
+use std::str::FromStr;
 
 fn main() {
-    let start = Instant::now();
-    part1();
-    part2();
-    println!("took: {} ms", start.elapsed().as_millis())
+    aoclib::solution::main::<Day18>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
+aoclib::solution!(Day18, 2021, 18);
 
+fn part1(input: &str) -> i64 {
     let snp: SnailFishPair = input.lines().nth(0).unwrap().parse().unwrap();
     let mut accum: Vec<(i8, Item)> = Vec::new();
     flatten(&snp, &mut accum, 0);
@@ -24,11 +21,11 @@ fn part1() {
         accum = result;
     }
 
-    println!("part1: {:?}", to_num(&magnitude(&accum, 3)[0].1));
+    to_num(&magnitude(&accum, 3)[0].1)
 }
 
-fn part2() {
-    let items: Vec<_> = include_str!("input.txt")
+fn part2(input: &str) -> i64 {
+    let items: Vec<_> = input
         .lines()
         .map(|line| line.parse::<SnailFishPair>().unwrap())
         .collect();
@@ -48,7 +45,7 @@ fn part2() {
         }
     }
 
-    println!("part2: {}", max);
+    max
 }
 
 #[test]
@@ -2,20 +2,18 @@
 use std::{
     collections::{HashMap, HashSet},
     hash::Hash,
-    time::Instant,
 };
 
 use itertools::Itertools;
 
 fn main() {
-    let start = Instant::now();
-    part1();
-    part2();
-    println!("took: {} ms", start.elapsed().as_millis())
+    aoclib::solution::main::<Day19>();
 }
 
-fn part1() {
-    let scanners = parse(include_str!("input.txt"));
+aoclib::solution!(Day19, 2021, 19);
+
+fn part1(input: &str) -> usize {
+    let scanners = parse(input);
 
     let distances = calculate_distances(&scanners);
     let mut path = sort_distances(&distances);
@@ -49,11 +47,11 @@ fn part1() {
     }
     let incl_dupes = positions.remove(&0).unwrap();
     let unique: HashSet<Position> = HashSet::from_iter(incl_dupes);
-    println!("part1: {}", unique.len());
+    unique.len()
 }
 
-fn part2() {
-    let scanners = parse(include_str!("input.txt"));
+fn part2(input: &str) -> i64 {
+    let scanners = parse(input);
 
     let distances = calculate_distances(&scanners);
     let mut path = sort_distances(&distances);
@@ -87,7 +85,7 @@ fn part2() {
         panic!("too many remaining keys: {:?}", positions.keys());
     }
 
-    let result = positions
+    positions
         .get(&0)
         .unwrap()
         .iter()
@@ -99,8 +97,7 @@ fn part2() {
             x_delta + y_delta + z_delta
         })
         .max()
-        .unwrap();
-    println!("pt2: {}", result);
+        .unwrap()
 }
 
 fn parse(s: &str) -> Vec<Scanner> {
@@ -1,43 +1,29 @@
 use core::panic;
-use std::{collections::HashSet, fmt::Debug, hash::Hash, iter::repeat, time::Instant};
+use std::{collections::HashSet, fmt::Debug, hash::Hash, iter::repeat};
 
 fn main() {
-    let start = Instant::now();
-    part1();
-    part2();
-    println!("took: {} ms", start.elapsed().as_millis())
+    aoclib::solution::main::<Day20>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
+aoclib::solution!(Day20, 2021, 20);
 
-    let image_enhancement_algo = ImageEnhancementBits::new(input.lines().next().unwrap());
-    let input_image = InputImage::new(input.lines().skip(2).collect());
-
-    let mut n = next(&input_image, &image_enhancement_algo);
-    println!("");
-    println!("{:?}", n);
-    println!("");
-    n = next(&n, &image_enhancement_algo);
-
-    println!("");
-    println!("{:?}", n);
-    println!("");
-
-    println!("{} pixels", n.light_pixels.len());
+fn part1(input: &str) -> usize {
+    enhance(input, 2)
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
+fn part2(input: &str) -> usize {
+    enhance(input, 50)
+}
 
+/// How many pixels are lit after enhancing the image `times`
+fn enhance(input: &str, times: usize) -> usize {
     let image_enhancement_algo = ImageEnhancementBits::new(input.lines().next().unwrap());
     let input_image = InputImage::new(input.lines().skip(2).collect());
 
-    let result = repeat(()).take(50).fold(input_image, |input, _| {
+    let result = repeat(()).take(times).fold(input_image, |input, _| {
         next(&input, &image_enhancement_algo)
     });
-    println!("Part2 Image:\n{:?}", result);
-    println!("Part2:\n{:?}", result.light_pixels.len());
+    result.light_pixels.len()
 }
 
 fn bin_to_i16(s: &str) -> i16 {
@@ -321,7 +307,10 @@ fn test_correct_count() {
     println!("35:\n{:?}", result);
     assert_eq!(result.light_pixels.len(), 35);
 
-    input = include_str!("input.txt");
+    let Some(real_input) = aoclib::input::load_or_skip(2021, 20) else {
+        return;
+    };
+    input = &real_input;
 
     image_enhancement_algo = ImageEnhancementBits::new(input.lines().next().unwrap());
     input_image = InputImage::new(input.lines().skip(2).collect());
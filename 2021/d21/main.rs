@@ -1,45 +1,19 @@
-use std::time::Instant;
-
 fn main() {
-    let start = Instant::now();
-    part1();
-    part2();
-    println!("took: {} ms", start.elapsed().as_millis())
+    aoclib::solution::main::<Day21>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
-
-    let player_one = input
-        .lines()
-        .nth(0)
-        .unwrap()
-        .chars()
-        .last()
-        .unwrap()
-        .to_digit(10)
-        .unwrap() as i64;
-    let player_two = input
-        .lines()
-        .nth(1)
-        .unwrap()
-        .chars()
-        .last()
-        .unwrap()
-        .to_digit(10)
-        .unwrap() as i64;
+aoclib::solution!(Day21, 2021, 21);
 
+fn part1(input: &str) -> i64 {
     let mut rolls = 0;
     let mut scores: [i64; 2] = [0, 0];
-    let mut positions: [i64; 2] = [player_one, player_two];
-    println!("starting on {:?}", positions);
+    let mut positions: [i64; 2] = starting_positions(input);
     loop {
         rolls += 3;
         let max_die = ((rolls - 1) % 100) + 1;
         let roll_one_score = max_die + (max_die - 1) + (max_die - 2);
         positions[0] = (((positions[0] - 1) + roll_one_score) % 10) + 1;
         scores[0] += positions[0];
-        println!("1 to pos. {} with score: {}", positions[0], roll_one_score);
 
         if scores[0] >= 1000 {
             break;
@@ -50,45 +24,29 @@ fn part1() {
         let roll_two_score = max_die + (max_die - 1) + (max_die - 2);
         positions[1] = (((positions[1] - 1) + roll_two_score) % 10) + 1;
         scores[1] += positions[1];
-        println!("2 to pos. {} with score: {}", positions[1], roll_two_score);
 
         if scores[1] >= 1000 {
             break;
         }
-
-        println!("2 to {}", positions[1]);
-        println!("rolls: {}: {:?}", rolls, scores);
     }
 
-    println!("rolls: {}", rolls);
     let min_score = i64::min(scores[0], scores[1]);
-    println!("min score: {}", min_score);
-    println!("pt1: {}", min_score * rolls);
+    min_score * rolls
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
-    let player_one = input
-        .lines()
-        .nth(0)
-        .unwrap()
-        .chars()
-        .last()
-        .unwrap()
-        .to_digit(10)
-        .unwrap() as i32;
-    let player_two = input
-        .lines()
-        .nth(1)
-        .unwrap()
-        .chars()
-        .last()
-        .unwrap()
-        .to_digit(10)
-        .unwrap() as i32;
+fn part2(input: &str) -> i64 {
+    let [player_one, player_two] = starting_positions(input);
 
-    let results = turn(true, [0, 0], [player_one, player_two], 1, 21);
-    println!("pt2: {:?}", i64::max(results[0], results[1]));
+    let results = turn(true, [0, 0], [player_one as i32, player_two as i32], 1, 21);
+    i64::max(results[0], results[1])
+}
+
+/// Where each player starts, from lines like `Player 1 starting position: 4`
+fn starting_positions(input: &str) -> [i64; 2] {
+    let mut players = input
+        .lines()
+        .map(|line| line.rsplit(' ').next().unwrap().parse::<i64>().unwrap());
+    [players.next().unwrap(), players.next().unwrap()]
 }
 
 fn turn(
@@ -156,3 +114,9 @@ fn test_pt2_example() {
     let result = turn(true, [0, 0], [4, 8], 1, 21);
     assert_eq!(result, [444356092776315, 341960390180808]);
 }
+
+#[test]
+fn test_pt1_example() {
+    let input = "Player 1 starting position: 4\nPlayer 2 starting position: 8";
+    assert_eq!(739785, part1(input));
+}
@@ -1,15 +1,12 @@
-use std::time::Instant;
+use aoclib::solution::NoAnswer;
 
 fn main() {
-    let start = Instant::now();
-    part1();
-    part2();
-    println!("took: {}ms", start.elapsed().as_millis())
+    aoclib::solution::main::<Day22>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
+aoclib::solution!(Day22, 2021, 22);
 
+fn part1(input: &str) -> i64 {
     let mut grid = vec![vec![vec![false; 101]; 101]; 101];
     let mut count: i64 = 0;
 
@@ -21,9 +18,7 @@ fn part1() {
         let (start_y, end_y) = parse_parts(axis.pop().unwrap());
         let (start_x, end_x) = parse_parts(axis.pop().unwrap());
 
-        if start_z < 0 || end_z > 100 || start_y < 0 || end_y > 100 || start_x < 0 || start_x > 100
-        {
-            println!("bad line: {}", line);
+        if start_z < 0 || end_z > 100 || start_y < 0 || end_y > 100 || start_x < 0 || end_x > 100 {
             continue;
         }
 
@@ -46,7 +41,7 @@ fn part1() {
             }
         }
     }
-    println!("pt1 result: {}", count);
+    count
 }
 
 fn parse_parts(axis_str: &str) -> (i64, i64) {
@@ -57,7 +52,6 @@ fn parse_parts(axis_str: &str) -> (i64, i64) {
     (start + 50, end + 50)
 }
 
-fn part2() {
-    let _ = include_str!("input.txt");
-    println!("result: {}", 1);
+fn part2(_input: &str) -> NoAnswer {
+    NoAnswer
 }
@@ -1,17 +1,18 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use aoclib::solution::NoAnswer;
 
 const WIDTH: usize = 12;
 
-fn main() -> io::Result<()> {
-    let lines = read_lines("./d3/input.txt")?;
+fn main() {
+    aoclib::solution::main::<Day3>();
+}
+
+aoclib::solution!(Day3, 2021, 3);
 
+fn part1(txt: &str) -> i32 {
     let mut bits: [i32; WIDTH] = [0; WIDTH];
     let mut count: i32 = 0;
 
-    for line in lines {
-        let item = line.unwrap();
+    for item in txt.lines() {
         let split: Vec<_> = item
             .split("")
             .filter_map(|char| char.parse::<i32>().ok())
@@ -21,8 +22,6 @@ fn main() -> io::Result<()> {
         }
         count += 1;
     }
-    println!("bits: {:?}", bits);
-    println!("count: {:?}", count);
     let hurdle = count / 2;
     let mut g_total = 0;
     let mut e_total = 0;
@@ -37,22 +36,9 @@ fn main() -> io::Result<()> {
         };
     }
 
-    println!(
-        "result: g:{}, e:{} {:?}",
-        g_total,
-        e_total,
-        g_total * e_total
-    );
-
-    Ok(())
+    g_total * e_total
 }
 
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+fn part2(_txt: &str) -> NoAnswer {
+    NoAnswer
 }
@@ -4,8 +4,22 @@ use std::{
 };
 
 fn main() {
-    let input = include_str!("input.txt");
+    aoclib::solution::main::<Day4>();
+}
+
+aoclib::solution!(Day4, 2021, 4);
+
+fn part1(txt: &str) -> i32 {
+    let (numbers, boards) = parse(txt);
+    part_one(numbers, boards).expect("a winning board")
+}
 
+fn part2(txt: &str) -> i32 {
+    let (numbers, boards) = parse(txt);
+    part_two(numbers, boards).expect("a winning board")
+}
+
+fn parse(input: &str) -> (Vec<i32>, Vec<Board>) {
     let mut lines = input.lines();
     let numbers: Vec<i32> = lines
         .next()
@@ -18,13 +32,7 @@ fn main() {
         .map(|line| line.trim())
         .filter(|line| line.len() > 0)
         .collect::<Vec<_>>();
-    let board_lines = remaining.chunks(5);
-
-    // println!("part one winner: {:?}", part_one(numbers, parse_boards(board_lines)));
-    println!(
-        "part two winner: {:?}",
-        part_two(numbers, parse_boards(board_lines))
-    );
+    (numbers, parse_boards(remaining.chunks(5)))
 }
 
 fn parse_boards(boards: Chunks<&str>) -> Vec<Board> {
@@ -40,7 +48,6 @@ fn parse_boards(boards: Chunks<&str>) -> Vec<Board> {
     parsed_boards
 }
 
-#[allow(dead_code)]
 fn part_one(numbers: Vec<i32>, mut boards: Vec<Board>) -> Option<i32> {
     for n in numbers {
         for b in &mut boards {
@@ -68,7 +75,6 @@ fn part_two(numbers: Vec<i32>, mut boards: Vec<Board>) -> Option<i32> {
             if let Some(winner) = b.remove(n) {
                 result = Some(winner * n);
                 winning_boards.insert(count);
-                println!("board {} won with {:?}", count + 1, result);
             }
             count += 1;
         }
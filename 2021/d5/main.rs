@@ -1,37 +1,54 @@
 use std::collections::HashSet;
 
 fn main() {
-    let input = include_str!("input.txt");
+    aoclib::solution::main::<Day5>();
+}
+
+aoclib::solution!(Day5, 2021, 5);
 
+fn part1(txt: &str) -> usize {
+    vent_collisions(txt, false)
+}
+
+fn part2(txt: &str) -> usize {
+    vent_collisions(txt, true)
+}
+
+fn vent_collisions(input: &str, diagonals: bool) -> usize {
     let mut vent_points: HashSet<Point> = HashSet::new();
     let mut vent_collision: HashSet<Point> = HashSet::new();
 
-    for vent_point in input.lines().flat_map(|l| {
-        let parts: Vec<&str> = l.split(" -> ").collect();
-        let left_point: Vec<&str> = parts[0].split(",").collect();
-        let right_point: Vec<&str> = parts[1].split(",").collect();
-
-        let left_x = left_point[0].parse().unwrap();
-        let left_y = left_point[1].parse().unwrap();
-        let right_x = right_point[0].parse().unwrap();
-        let right_y = right_point[1].parse().unwrap();
-        LineSegment(
-            Point {
-                x: left_x,
-                y: left_y,
-            },
-            Point {
-                x: right_x,
-                y: right_y,
-            },
-        )
-    }) {
+    for vent_point in input
+        .lines()
+        .map(|l| {
+            let parts: Vec<&str> = l.split(" -> ").collect();
+            let left_point: Vec<&str> = parts[0].split(",").collect();
+            let right_point: Vec<&str> = parts[1].split(",").collect();
+
+            let left_x = left_point[0].parse().unwrap();
+            let left_y = left_point[1].parse().unwrap();
+            let right_x = right_point[0].parse().unwrap();
+            let right_y = right_point[1].parse().unwrap();
+            LineSegment(
+                Point {
+                    x: left_x,
+                    y: left_y,
+                },
+                Point {
+                    x: right_x,
+                    y: right_y,
+                },
+            )
+        })
+        .filter(|l| diagonals || l.0.x == l.1.x || l.0.y == l.1.y)
+        .flatten()
+    {
         if !vent_points.insert(vent_point) {
             vent_collision.insert(vent_point);
         }
     }
 
-    println!("vent_collisions: {:?}", vent_collision.len());
+    vent_collision.len()
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
@@ -134,3 +151,10 @@ fn line_segment_along_diagonal_complex_into_iter() {
         v
     );
 }
+
+#[test]
+fn example() {
+    let input = include_str!("input.test.txt");
+    assert_eq!(5, part1(input));
+    assert_eq!(12, part2(input));
+}
@@ -1,13 +1,25 @@
 fn main() {
-    let input = include_str!("input.txt");
+    aoclib::solution::main::<Day6>();
+}
+
+aoclib::solution!(Day6, 2021, 6);
+
+fn part1(txt: &str) -> i64 {
+    lanternfish(txt, 80)
+}
+
+fn part2(txt: &str) -> i64 {
+    lanternfish(txt, 256)
+}
+
+fn lanternfish(input: &str, days: usize) -> i64 {
     let mut counts: [i64; 9] = [0; 9];
 
-    for n in input.split(",").map(|c| c.parse::<usize>().unwrap()) {
+    for n in input.trim().split(",").map(|c| c.parse::<usize>().unwrap()) {
         counts[n] += 1;
     }
-    let day = 256;
 
-    for _ in 0..day {
+    for _ in 0..days {
         let carry = counts[0];
         for bucket in 0..8 {
             counts[bucket] = counts[bucket + 1];
@@ -15,6 +27,12 @@ fn main() {
         counts[6] += carry;
         counts[8] = carry;
     }
-    let answer: i64 = counts.iter().sum();
-    println!("answer: {}", answer)
+    counts.iter().sum()
+}
+
+#[test]
+fn example() {
+    let input = include_str!("input.test.txt");
+    assert_eq!(5934, part1(input));
+    assert_eq!(26984457539, part2(input));
 }
@@ -1,16 +1,16 @@
 fn main() {
-    part1();
-    part2();
+    aoclib::solution::main::<Day7>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
+aoclib::solution!(Day7, 2021, 7);
+
+fn part1(input: &str) -> i64 {
     let positions: Vec<_> = input
+        .trim()
         .split(",")
         .map(|c| c.parse::<i64>().unwrap())
         .collect();
     let max = (&positions).iter().max().unwrap().to_owned() as usize;
-    println!("max: {}", max);
 
     let mut counts: Vec<i64> = vec![0; max];
 
@@ -19,18 +19,16 @@ fn part1() {
             counts[i] += (pos - i as i64).abs();
         }
     }
-    let min = (&counts).iter().min().unwrap();
-    println!("min: {}", min);
+    *(&counts).iter().min().unwrap()
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
+fn part2(input: &str) -> i64 {
     let positions: Vec<_> = input
+        .trim()
         .split(",")
         .map(|c| c.parse::<i64>().unwrap())
         .collect();
     let max = (&positions).iter().max().unwrap().to_owned() as usize;
-    println!("max: {}", max);
 
     let mut counts: Vec<i64> = vec![0; max];
 
@@ -41,6 +39,12 @@ fn part2() {
             counts[i] += triangle_n;
         }
     }
-    let min = (&counts).iter().min().unwrap();
-    println!("min: {}", min);
+    *(&counts).iter().min().unwrap()
+}
+
+#[test]
+fn example() {
+    let input = include_str!("input.test.txt");
+    assert_eq!(37, part1(input));
+    assert_eq!(168, part2(input));
 }
@@ -1,22 +1,20 @@
 use std::collections::{BTreeSet, HashMap, HashSet};
 
 fn main() {
-    part1();
-    part2();
+    aoclib::solution::main::<Day8>();
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
+aoclib::solution!(Day8, 2021, 8);
+
+fn part1(input: &str) -> i32 {
     let uniq_vec = vec![2, 4, 3, 7];
     let unique: HashSet<_> = HashSet::from_iter(uniq_vec.iter());
     let mut count = 0;
 
     for line in input.lines() {
         let parts: Vec<_> = line.split(" | ").collect();
-        println!("parts: {:?}", parts);
         let _signal = parts[0];
         let output = parts[1];
-        println!("output: {:?}", output);
         let items = output
             .split(" ")
             .map(|item| item.len() as i32)
@@ -25,17 +23,11 @@ fn part1() {
         count += items as i32;
     }
 
-    println!("count: {}", count);
+    count
 }
 
-fn part2() {
-    let input = include_str!("input.txt");
-
-    let mut sum = 0;
-    for line in input.lines() {
-        sum += line_to_number(line);
-    }
-    println!("part2: {}", sum)
+fn part2(input: &str) -> i64 {
+    input.lines().map(line_to_number).sum()
 }
 
 fn line_to_number(line: &str) -> i64 {
@@ -206,14 +198,6 @@ fn build_positions(left: &str) -> [char; 7] {
     }
     known_positions[4] = *eight.iter().next().unwrap();
 
-    println!("");
-    println!("  {} ", known_positions[0]);
-    println!("{}  {}", known_positions[1], known_positions[2]);
-    println!("  {} ", known_positions[3]);
-    println!("{}  {}", known_positions[4], known_positions[5]);
-    println!("  {} ", known_positions[6]);
-    println!("");
-
     return known_positions;
 }
 
@@ -230,3 +214,10 @@ fn intersection_all(sets: Vec<&HashSet<char>>) -> HashSet<char> {
 
     first
 }
+
+#[test]
+fn example() {
+    let input = include_str!("input.test.txt");
+    assert_eq!(26, part1(input));
+    assert_eq!(61229, part2(input));
+}
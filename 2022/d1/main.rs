@@ -1,11 +1,30 @@
 use std::collections::BinaryHeap;
 
+use aoclib::{Answer, solution::Solution};
+
 fn main() {
-    let input = include_str!("input.txt");
-    let result = max(input);
-    println!("max: {result}");
-    let result_n = sum_max_n(input, 3);
-    println!("max n: {result_n}");
+    aoclib::solution::main::<Day1>();
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        max(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        sum_max_n(input, 3)
+    }
 }
 
 fn max(input: &str) -> i32 {
@@ -42,7 +61,7 @@ fn sum_max_n(input: &str, n: usize) -> i32 {
     let mut sum = 0;
     while count > 0 {
         if let Some(i) = heap.pop() {
-            println!("got: {i}");
+            eprintln!("got: {i}");
             sum += i;
         }
         count -= 1;
@@ -1,10 +1,30 @@
 use std::{num::ParseIntError, str::FromStr};
 
+use aoclib::{Answer, grid::Grid, ocr, solution::Solution};
+
 fn main() {
-    let input = include_str!("input.txt");
-    println!("part1: {}", part1(input));
-    let result = part2(input);
-    print_crt(&result);
+    aoclib::solution::main::<Day10>();
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+
+    type Input<'a> = &'a str;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        part2(input)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -95,7 +115,24 @@ fn part1(input: &str) -> i32 {
         .sum()
 }
 
-fn part2(input: &str) -> [[char; 40]; 6] {
+/// The letters on the screen, or the screen itself when they can't be read
+fn part2(input: &str) -> Answer {
+    let crt = crt(input);
+    let screen = Grid::new(
+        crt.iter()
+            .map(|line| line.iter().map(|c| *c == '#').collect())
+            .collect(),
+    );
+    match ocr::read_letters(&screen) {
+        Ok(letters) => Answer::Text(letters),
+        Err(e) => {
+            eprintln!("{e}");
+            crt.into()
+        }
+    }
+}
+
+fn crt(input: &str) -> [[char; 40]; 6] {
     let instructions = input
         .lines()
         .map(|line| line.parse::<Instruction>().unwrap())
@@ -120,12 +157,12 @@ fn part2(input: &str) -> [[char; 40]; 6] {
 
             let cycle = (i + 1) * (j + 1);
             if cycle < 30 {
-                println!("Cycle: {}", cycle);
-                println!("Register Val: {}", register_val);
-                println!("Pixel: {}", j);
-                println!("Sprite Position: {:?}", sprite_vals);
+                eprintln!("Cycle: {}", cycle);
+                eprintln!("Register Val: {}", register_val);
+                eprintln!("Pixel: {}", j);
+                eprintln!("Sprite Position: {:?}", sprite_vals);
                 print_crt(&crt_lines);
-                println!("");
+                eprintln!("");
             }
         }
     }
@@ -135,15 +172,19 @@ fn part2(input: &str) -> [[char; 40]; 6] {
 fn print_crt(crt: &[[char; 40]; 6]) {
     for line in crt {
         for col in line {
-            print!("{}", col);
+            eprint!("{}", col);
         }
-        println!("");
+        eprintln!("");
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{Instruction, Register, part1, part2, print_crt, process_instructions};
+    use aoclib::solution::Part;
+
+    use super::{
+        Answer, Day10, Instruction, Register, crt, part1, part2, print_crt, process_instructions,
+    };
 
     #[test]
     fn test_parse() {
@@ -181,7 +222,14 @@ mod test {
     #[test]
     fn test_part2_example() {
         let input = include_str!("input.example.txt");
-        let result = part2(input);
+        let result = crt(input);
         print_crt(&result);
+        // the example draws stripes rather than letters, so the screen is the answer
+        assert_eq!(Answer::from(result), part2(input));
+    }
+
+    #[test]
+    fn input_pt2() {
+        aoclib::answers::assert_correct::<Day10>(Part::Two);
     }
 }
@@ -1,13 +1,11 @@
 use std::{collections::HashMap, str::FromStr};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let part1_result = part1(input);
-    println!("part1: {}", part1_result);
-    let part2_result = part2(input);
-    println!("part2: {}", part2_result);
+    aoclib::solution::main::<Day11>();
 }
 
+aoclib::solution!(Day11, 2022, 11);
+
 #[derive(Debug, PartialEq, Clone)]
 struct Monkey {
     id: usize,
@@ -326,7 +324,7 @@ fn part2(input: &str) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn parse_operation() {
@@ -493,14 +491,14 @@ mod tests {
         play(&mut monkeys, &mut counts, &DivideWorry(3));
         assert_eq!(monkeys[0].items, vec![20, 23, 27, 26]);
         assert_eq!(monkeys[1].items, vec![2080, 25, 167, 207, 401, 1046]);
-        assert_eq!(monkeys[2].items, vec![]);
-        assert_eq!(monkeys[3].items, vec![]);
+        assert_eq!(monkeys[2].items, Vec::<i64>::new());
+        assert_eq!(monkeys[3].items, Vec::<i64>::new());
 
         play(&mut monkeys, &mut counts, &DivideWorry(3));
         assert_eq!(monkeys[0].items, vec![695, 10, 71, 135, 350]);
         assert_eq!(monkeys[1].items, vec![43, 49, 58, 55, 362]);
-        assert_eq!(monkeys[2].items, vec![]);
-        assert_eq!(monkeys[3].items, vec![]);
+        assert_eq!(monkeys[2].items, Vec::<i64>::new());
+        assert_eq!(monkeys[3].items, Vec::<i64>::new());
     }
 
     #[test]
@@ -7,13 +7,11 @@ use aoclib::{
 };
 
 fn main() {
-    let input = include_str!("input.txt");
-    let pt1 = part1(input);
-    println!("pt1: {}", pt1);
-    let pt2 = part2(input);
-    println!("pt2: {}", pt2);
+    aoclib::solution::main::<Day12>();
 }
 
+aoclib::solution!(Day12, 2022, 12);
+
 fn part1(input: &str) -> usize {
     let parsed: HeightMap = input.parse().unwrap();
     let start = &parsed.start;
@@ -38,8 +36,7 @@ fn part2(input: &str) -> i64 {
 
     parsed
         .map
-        .rows
-        .iter()
+        .rows()
         .enumerate()
         .flat_map(|(row_num, row)| {
             row.iter().enumerate().filter_map(move |(col_num, h)| {
@@ -106,7 +103,7 @@ impl FromStr for HeightMap {
         let mut start: Option<GridPosition> = None;
         let mut end: Option<GridPosition> = None;
 
-        for (row_num, row) in map.rows.iter().enumerate() {
+        for (row_num, row) in map.rows().enumerate() {
             for (col_num, col) in row.iter().enumerate() {
                 match col {
                     MapPoint::Start => {
@@ -150,7 +147,7 @@ impl Neighbours<GridPosition> for HeightMap {
 mod tests {
     use itertools::Itertools;
 
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_parse_input() {
@@ -162,8 +159,7 @@ mod tests {
         assert_eq!(expected_end, parsed.end);
         let heights = parsed
             .map
-            .rows
-            .iter()
+            .rows()
             .rev()
             .map(|row| row.iter().map(|col| col.height()).collect_vec())
             .collect_vec();
@@ -198,7 +194,7 @@ mod tests {
         let heuristic = ManhattenDistanceTo(*end);
 
         let end_check = |p: &GridPosition| {
-            // println!("checking: {:?} to be {:?}", m.to, end);
+            // eprintln!("checking: {:?} to be {:?}", m.to, end);
             p == end
         };
 
@@ -3,13 +3,11 @@ use std::{fmt::Debug, str::FromStr};
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let part1 = part1(input);
-    println!("part1: {}", part1);
-    let part2 = part2(input);
-    println!("part2: {}", part2);
+    aoclib::solution::main::<Day13>();
 }
 
+aoclib::solution!(Day13, 2022, 13);
+
 #[derive(Debug, PartialEq)]
 enum Order {
     Correct,
@@ -46,7 +44,7 @@ impl Debug for PacketItem {
 
 impl PacketItem {
     fn order(&self, right: &Self) -> Order {
-        // println!("comparing {:?} vs {:?}", self, right);
+        // eprintln!("comparing {:?} vs {:?}", self, right);
         match self {
             PacketItem::SimplePacketItem(left_i) => match right {
                 PacketItem::SimplePacketItem(right_i) => {
@@ -114,7 +112,7 @@ fn parse(s: &str, index: usize, mut stack: Vec<Vec<PacketItem>>) -> Vec<Vec<Pack
     } else if current_str == "]" {
         let left = &s[..index];
         let right = &s[index + 1..];
-        // println!("] with {:?} - {:?} - d{}", left, right, stack.len());
+        // eprintln!("] with {:?} - {:?} - d{}", left, right, stack.len());
         let mut left_items = parse(left, 0, stack);
         let it = left_items.pop().map(PacketItem::ComplexPacketItem);
         let mut top = left_items.pop().unwrap();
@@ -124,7 +122,7 @@ fn parse(s: &str, index: usize, mut stack: Vec<Vec<PacketItem>>) -> Vec<Vec<Pack
     } else if current_str == "," {
         let left = &s[..index];
         let right = &s[index + 1..];
-        // println!(", with {:?} - {:?} - d{}", left, right, stack.len());
+        // eprintln!(", with {:?} - {:?} - d{}", left, right, stack.len());
         let left_items = parse(left, 0, stack);
         parse(right, 0, left_items)
     } else {
@@ -144,9 +142,9 @@ fn part1(input: &str) -> i64 {
         })
         .collect();
     for pair in &items {
-        println!("{:?}", pair[0]);
-        println!("{:?}", pair[1]);
-        println!();
+        eprintln!("{:?}", pair[0]);
+        eprintln!("{:?}", pair[1]);
+        eprintln!();
     }
     items
         .into_iter()
@@ -203,6 +201,7 @@ fn part2(input: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_parse_base_case() {
@@ -333,9 +332,7 @@ mod tests {
 
     #[test]
     fn test_parse_my_input() {
-        let input = include_str!("input.txt");
-        let result = part1(input);
-        assert_eq!(6272, result);
+        aoclib::answers::assert_correct::<Day13>(Part::One);
     }
 
     #[test]
@@ -3,13 +3,11 @@ use std::{collections::HashSet, str::FromStr};
 use aoclib::cartesian::{Point, Transform, Vector};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let part1 = part1(input);
-    println!("part1: {}", part1);
-    let part1 = part2(input);
-    println!("part2: {}", part1);
+    aoclib::solution::main::<Day14>();
 }
 
+aoclib::solution!(Day14, 2022, 14);
+
 #[derive(Debug, PartialEq)]
 struct Cave {
     sand: HashSet<Point>,
@@ -19,7 +17,7 @@ struct Cave {
 
 impl Cave {
     fn try_add_point(self, p: Point) -> Result<Self, (Point, Self)> {
-        // println!("{:?}", p);
+        // eprintln!("{:?}", p);
         // we're swapping down for up, since our axis is positive up, negative down
         if p.y >= self.max_depth {
             return Err((p, self));
@@ -125,26 +123,24 @@ fn part2(input: &str) -> usize {
 
 #[cfg(test)]
 mod test {
-    use aoclib::cartesian::Plane;
+    use aoclib::{cartesian::Plane, grid::SparseGrid};
 
     use super::*;
 
     fn draw(c: &Cave, p: &Plane) {
+        let mut cave = SparseGrid::new('.');
         for y in p.top_left.y..=p.bottom_right.y {
             for x in p.top_left.x..=p.bottom_right.x {
                 let point: Point = (x, y).into();
-                let is_sand = c.sand.contains(&point);
-                let is_wall = !is_sand && c.rock_paths.iter().any(|path| point.on(path));
-                if is_wall {
-                    print!("#")
-                } else if is_sand {
-                    print!("O")
-                } else {
-                    print!(".")
+                if c.rock_paths.iter().any(|path| point.on(path)) {
+                    cave.insert(point, '#');
                 }
             }
-            println!("")
         }
+        for sand in &c.sand {
+            cave.insert(sand.clone(), 'O');
+        }
+        eprint!("{cave}");
     }
 
     #[test]
@@ -182,15 +178,15 @@ mod test {
                 Result::Ok(c) => {
                     cave = c;
                     count += 1;
-                    // println!("{} sand added", count);
+                    // eprintln!("{} sand added", count);
                 }
                 Result::Err(_) => {
-                    // println!("overflow at {:?}", overflow);
+                    // eprintln!("overflow at {:?}", overflow);
                     break;
                 }
             }
             draw(&cave, &plane);
-            println!()
+            eprintln!()
         }
         assert_eq!(24, count);
     }
@@ -1,20 +1,41 @@
-use std::{collections::HashSet, fmt::Debug, str::FromStr, time::Instant};
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    str::FromStr,
+};
 
 use aoclib::{
+    Answer,
     cartesian::{Plane, Point, Transform},
     distance::{Distance, ManhattenDistance},
+    solution::Solution,
 };
 
 use rayon::prelude::*;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let part1 = part1(input, 2000000);
-    println!("part1: {:?}", part1);
-    let start = Instant::now();
-    let part2 = part2(input, (4000000, 4000000).into());
-    let duration = start.elapsed();
-    println!("part2: {:?} - took {:?}", part2, duration);
+    aoclib::solution::main::<Day15>();
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+
+    type Input<'a> = &'a str;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        part1(input, 2000000)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        part2(input, (4000000, 4000000).into())
+    }
 }
 
 struct Sensor {
@@ -229,7 +250,7 @@ impl ExclusionArea {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_parse() {
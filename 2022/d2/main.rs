@@ -1,11 +1,30 @@
 use std::str::FromStr;
 
+use aoclib::{Answer, solution::Solution};
+
 fn main() {
-    let input = include_str!("input.txt");
-    let total: i32 = total_score(input);
-    println!("total score: {total}");
-    let total_2: i32 = total_score_2(input);
-    println!("total score 2: {total_2}");
+    aoclib::solution::main::<Day2>();
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    type Input<'a> = &'a str;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        total_score(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        total_score_2(input)
+    }
 }
 
 #[derive(Debug, PartialEq)]
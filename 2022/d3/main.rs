@@ -1,14 +1,31 @@
 use std::collections::HashSet;
 
+use aoclib::{Answer, solution::Solution};
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let total: i32 = total_priorities(input);
-    println!("total: {total}");
+    aoclib::solution::main::<Day3>();
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
 
-    let total_chunk: i32 = chunk_3(input);
-    println!("part2: {total_chunk}")
+    type Input<'a> = &'a str;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        total_priorities(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        chunk_3(input)
+    }
 }
 
 fn char_priority(c: char) -> i32 {
@@ -1,9 +1,28 @@
+use aoclib::{Answer, solution::Solution};
+
 fn main() {
-    let input = include_str!("input.txt");
-    let part1 = count_where_overlap(input);
-    println!("part1: {part1}");
-    let part2 = count_overlaps(input);
-    println!("part2: {part2}");
+    aoclib::solution::main::<Day4>();
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input<'a> = &'a str;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        count_where_overlap(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        count_overlaps(input)
+    }
 }
 
 fn count_where_overlap(input: &str) -> i32 {
@@ -1,14 +1,30 @@
 use std::str::FromStr;
 
+use aoclib::{Answer, solution::Solution};
+
 fn main() {
-    let input = include_str!("input.txt");
-    let parsed: Input = input.parse().unwrap();
-    let pt1_crane = CrateMover9000 {};
-    let pt1 = solve(pt1_crane, parsed.clone());
-    println!("part1: {:?}", pt1);
-    let pt2_crane = CrateMover9001 {};
-    let pt2 = solve(pt2_crane, parsed);
-    println!("part2: {:?}", pt2);
+    aoclib::solution::main::<Day5>();
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Input;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt.parse().unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        solve(CrateMover9000 {}, input.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        solve(CrateMover9001 {}, input.clone())
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -27,10 +43,10 @@ struct Move {
 }
 
 #[derive(Debug, PartialEq, Clone)]
-struct Input(Stacks, Vec<Move>);
+pub struct Input(Stacks, Vec<Move>);
 
 #[derive(Debug, PartialEq)]
-enum InputError {}
+pub enum InputError {}
 
 impl FromStr for Input {
     type Err = InputError;
@@ -1,31 +1,41 @@
 use std::{collections::HashMap, slice::Iter};
 
+use aoclib::{Answer, solution::Solution};
+
 fn main() {
-    let input = include_str!("input.txt");
-    let root = parse(input);
-    let found = find_dirs_smaller_than(&root, 100000);
+    aoclib::solution::main::<Day7>();
+}
 
-    println!("root: {:?}    ", root);
-    println!("found: {}", found.len());
+pub struct Day7;
 
-    let total_size: i32 = found.iter().map(|dir| dir.size()).sum();
-    println!("part1: {}", total_size);
-
-    let total_disk_space = 70000000;
-    let required_space = 30000000;
-    let used_space = root.size();
-    let free_space = total_disk_space - used_space;
-    let required_to_free = required_space - free_space;
-    let mut dirs_that_could_make_space = find_dirs_larger_than(&root, required_to_free);
-    dirs_that_could_make_space.sort_by(|a, b| a.size().partial_cmp(&b.size()).unwrap());
-    println!("part2");
-    println!("used: \t\t\t\t{}", used_space);
-    println!("free: \t\t\t\t{}", free_space);
-    println!("required to free: \t\t{}", required_to_free);
-    println!(
-        "smallest satisfying: \t\t{}",
-        dirs_that_could_make_space[0].size()
-    );
+impl Solution for Day7 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Dir;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        parse(txt)
+    }
+
+    fn part1(root: &Self::Input<'_>) -> impl Into<Answer> {
+        find_dirs_smaller_than(root, 100000)
+            .iter()
+            .map(|dir| dir.size())
+            .sum::<i32>()
+    }
+
+    fn part2(root: &Self::Input<'_>) -> impl Into<Answer> {
+        let total_disk_space = 70000000;
+        let required_space = 30000000;
+        let free_space = total_disk_space - root.size();
+        let required_to_free = required_space - free_space;
+        find_dirs_larger_than(root, required_to_free)
+            .iter()
+            .map(|dir| dir.size())
+            .min()
+            .unwrap()
+    }
 }
 
 fn to_command_chunks(input: &str) -> Vec<Vec<&str>> {
@@ -48,8 +58,8 @@ fn parse(input: &str) -> Dir {
 
 fn process_commands(mut commands: Iter<Vec<&str>>, mut cwd: Dir, mut parents: Vec<Dir>) -> Dir {
     if let Some(command) = commands.next() {
-        // println!("processing: {:?}", command);
-        // println!("with: \n\t{:?}\n\t{:?}", cwd, parents);
+        // eprintln!("processing: {:?}", command);
+        // eprintln!("with: \n\t{:?}\n\t{:?}", cwd, parents);
         let mut command_itr = command.iter();
         let command = command_itr.next().unwrap();
         if command.starts_with("cd") {
@@ -79,8 +89,8 @@ fn process_commands(mut commands: Iter<Vec<&str>>, mut cwd: Dir, mut parents: Ve
             return process_commands(commands, cwd, parents);
         }
     }
-    // println!("going back up stack");
-    // println!("cwd:\n{:?}\n", cwd.name);
+    // eprintln!("going back up stack");
+    // eprintln!("cwd:\n{:?}\n", cwd.name);
 
     if let Some(mut parent) = parents.pop() {
         parent.add_dir(cwd);
@@ -92,7 +102,7 @@ fn process_commands(mut commands: Iter<Vec<&str>>, mut cwd: Dir, mut parents: Ve
 
 fn find_dirs_smaller_than(dir: &Dir, size: i32) -> Vec<&Dir> {
     let mut matching: Vec<&Dir> = Vec::new();
-    // println!("scanning children of {:?}: {:?}",
+    // eprintln!("scanning children of {:?}: {:?}",
     //     dir.name,
     //     dir.child_dirs.values().map(|c|&c.name).collect::<Vec<_>>());
 
@@ -108,7 +118,7 @@ fn find_dirs_smaller_than(dir: &Dir, size: i32) -> Vec<&Dir> {
 
 fn find_dirs_larger_than(dir: &Dir, size: i32) -> Vec<&Dir> {
     let mut matching: Vec<&Dir> = Vec::new();
-    // println!("scanning children of {:?}: {:?}",
+    // eprintln!("scanning children of {:?}: {:?}",
     //     dir.name,
     //     dir.child_dirs.values().map(|c|&c.name).collect::<Vec<_>>());
 
@@ -133,7 +143,7 @@ struct File {
 }
 
 #[derive(Debug, PartialEq, Clone)]
-struct Dir {
+pub struct Dir {
     name: String,
     child_dirs: HashMap<String, Dir>,
     child_files: Vec<File>,
@@ -213,5 +223,5 @@ fn test_part1_example() {
 fn test_parse_repeats() {
     let input = include_str!("example.repeat.txt");
     let root = parse(input);
-    println!("\n\nwith repeats:\n{:?}", root);
+    eprintln!("\n\nwith repeats:\n{:?}", root);
 }
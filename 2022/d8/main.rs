@@ -1,14 +1,32 @@
 use std::collections::HashSet;
 
+use aoclib::{Answer, solution::Solution};
+
 fn main() {
-    let input = include_str!("input.txt");
-    let visible = resolve_visible(input);
-    println!("part1: {}", visible.len());
+    aoclib::solution::main::<Day8>();
+}
 
-    let heights = parse_heights(input);
-    let scores = build_scenic_score_grid(&heights);
-    let max_score = scores.iter().flat_map(|row| row).max().unwrap();
-    println!("part2: {}", max_score);
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type Input<'a> = &'a str;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        resolve_visible(input).len()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        let heights = parse_heights(input);
+        let scores = build_scenic_score_grid(&heights);
+        *scores.iter().flat_map(|row| row).max().unwrap()
+    }
 }
 
 #[test]
@@ -24,24 +42,24 @@ fn test_pt2_example() {
     let input = include_str!("input.example.txt");
     let heights = parse_heights(input);
 
-    println!("\nheights");
+    eprintln!("\nheights");
 
     for height_row in &heights {
         for height_col in height_row {
-            print!(" {} ", height_col);
+            eprint!(" {} ", height_col);
         }
-        println!("");
+        eprintln!("");
     }
 
     let scores = build_scenic_score_grid(&heights);
 
-    println!("\nscores {}x{}", scores.len(), scores[0].len());
+    eprintln!("\nscores {}x{}", scores.len(), scores[0].len());
 
     for y in 0..scores.len() {
         for x in 0..scores[0].len() {
-            print!(" {} ", scores[y][x]);
+            eprint!(" {} ", scores[y][x]);
         }
-        println!("");
+        eprintln!("");
     }
 
     assert_eq!(4, scores[1][2], "1,2");
@@ -51,12 +69,12 @@ fn test_pt2_example() {
 #[cfg(test)]
 fn print_visible(visible: &HashSet<(usize, usize)>, width: usize, height: usize) {
     for i in 0..=height {
-        println!("");
+        eprintln!("");
         for j in 0..=width {
             if visible.contains(&(i, j)) {
-                print!("*");
+                eprint!("*");
             } else {
-                print!(" ");
+                eprint!(" ");
             }
         }
     }
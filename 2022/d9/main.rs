@@ -1,12 +1,11 @@
 use std::{collections::HashSet, str::FromStr};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let pt1_results = part1(input);
-    println!("pt1: {}", pt1_results);
-    println!("pt2: {}", part2(input));
+    aoclib::solution::main::<Day9>();
 }
 
+aoclib::solution!(Day9, 2022, 9);
+
 fn part1(input: &str) -> usize {
     let mut head = Head {
         pos: (0, 0),
@@ -496,9 +495,9 @@ mod test {
                 loop {
                     if current_head.pos == (i, j) {
                         if depth == 0 {
-                            print!("H")
+                            eprint!("H")
                         } else {
-                            print!("{}", depth);
+                            eprint!("{}", depth);
                         }
                         found = true;
                         break;
@@ -512,15 +511,15 @@ mod test {
                 }
                 if !found {
                     if (0, 0) == (i, j) {
-                        print!("s");
+                        eprint!("s");
                     } else if seen.contains(&(i, j)) {
-                        print!("#");
+                        eprint!("#");
                     } else {
-                        print!(".");
+                        eprint!(".");
                     }
                 }
             }
-            println!("");
+            eprintln!("");
         }
     }
 
@@ -549,10 +548,10 @@ mod test {
     fn debug_step(move_line: &str, head: &mut Head, move_listener: &mut MoveTracker) {
         let m = move_line.parse::<Move>().unwrap();
         head.apply(m.clone(), move_listener);
-        println!("positions after {:?}", m);
+        eprintln!("positions after {:?}", m);
         print_trail(6, 6, &head, &move_listener.tail_positions);
-        println!("");
-        println!("");
+        eprintln!("");
+        eprintln!("");
     }
 
     #[test]
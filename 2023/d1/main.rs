@@ -1,15 +1,13 @@
-use std::{collections::BTreeMap, time::Instant};
+use std::collections::BTreeMap;
 
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day1>();
 }
 
+aoclib::solution!(Day1, 2023, 1);
+
 fn part1(txt: &str) -> i32 {
     const RADIX: u32 = 10;
 
@@ -21,7 +19,7 @@ fn part1(txt: &str) -> i32 {
         })
         .map(|l| {
             // if (l.len() < 2) {
-            // println!("bad: {:?}", l);
+            // eprintln!("bad: {:?}", l);
             // }
             format!("{}{}", l.first().unwrap(), l.last().unwrap())
         })
@@ -71,7 +69,7 @@ fn part2(txt: &str) -> i32 {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn sample_input_pt1() {
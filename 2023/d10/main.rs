@@ -1,16 +1,14 @@
-use std::{collections::HashSet, i64, str::FromStr, time::Instant};
+use std::{collections::HashSet, i64, str::FromStr};
 
 use aoclib::cartesian::{Plane, Point};
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day10>();
 }
 
+aoclib::solution!(Day10, 2023, 10);
+
 fn part1(txt: &str) -> usize {
     let g: Grid = txt.parse().expect("valid grid");
     let mut start_pos = None;
@@ -131,7 +129,7 @@ fn part2(txt: &str) -> usize {
                 if edge_crosses % 2 != 0 {
                     // odd, so inside the edges
                     count += 1;
-                    // println!("{:?} inside with {:?} and {:?}", p, edge_crosses, g.at(&p));
+                    // eprintln!("{:?} inside with {:?} and {:?}", p, edge_crosses, g.at(&p));
                 }
             }
         }
@@ -148,7 +146,7 @@ fn next<'a>(g: &Grid, next: Point, plane: &Plane, seen: &mut HashSet<Point>) ->
 
     let tile = g.at(&next);
     let connections = tile.connects();
-    // println!("{:?} connects on {:?}", next, connections);
+    // eprintln!("{:?} connects on {:?}", next, connections);
     for c in connections {
         match c {
             Connects::Down => {
@@ -311,7 +309,7 @@ impl FromStr for Grid {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
@@ -341,6 +339,6 @@ mod tests {
 
         let mut seen = HashSet::new();
         path(&g, p_one, &plane, &mut seen);
-        println!("p: {:?}", seen);
+        eprintln!("p: {:?}", seen);
     }
 }
@@ -1,4 +1,4 @@
-use std::{collections::HashSet, i64, str::FromStr, time::Instant};
+use std::{collections::HashSet, i64, str::FromStr};
 
 use aoclib::{
     cartesian::Point,
@@ -7,13 +7,11 @@ use aoclib::{
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day11>();
 }
 
+aoclib::solution!(Day11, 2023, 11);
+
 fn part1(txt: &str) -> i64 {
     calc(txt, 2)
 }
@@ -111,7 +109,7 @@ impl FromStr for Map {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
@@ -1,15 +1,13 @@
-use std::{collections::HashMap, str::FromStr, time::Instant};
+use std::{collections::HashMap, str::FromStr};
 
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day12>();
 }
 
+aoclib::solution!(Day12, 2023, 12);
+
 fn part1(txt: &str) -> usize {
     txt.lines()
         .map(|l| l.parse::<Springs>().unwrap().check())
@@ -50,7 +48,7 @@ fn check<'a>(
     }
 
     if n.is_empty() {
-        // println!("** here empty");
+        // eprintln!("** here empty");
         if str.contains("#") {
             memo.insert((str, n), 0);
             return 0;
@@ -59,13 +57,13 @@ fn check<'a>(
         return 1;
     }
     if str.is_empty() {
-        // println!("here empty empty");
+        // eprintln!("here empty empty");
         memo.insert((str, n), 0);
         return 0;
     }
 
     if &str[0..1] == "." {
-        // println!("here dot");
+        // eprintln!("here dot");
         return check(str[1..].to_owned(), n, memo);
     }
 
@@ -87,13 +85,13 @@ fn check<'a>(
     if &str[0..1] == "#" && str_len >= n[0] {
         let left = &str[0..n[0]];
         if left.contains(&".") {
-            // println!("here contains dot [{}]", n[0]);
+            // eprintln!("here contains dot [{}]", n[0]);
             memo.insert((str, n), 0);
             return 0;
         }
 
         if str_len == n[0] && n.len() == 1 {
-            // println!("** here len match 1");
+            // eprintln!("** here len match 1");
             memo.insert((str, n), 1);
             return 1;
         }
@@ -102,7 +100,7 @@ fn check<'a>(
 
         if right.is_empty() {
             if n.len() == 1 {
-                // println!("** here len match 2");
+                // eprintln!("** here len match 2");
                 memo.insert((str, n), 1);
                 return 1;
             } else {
@@ -112,21 +110,21 @@ fn check<'a>(
         }
 
         if !right.is_empty() && &right[0..1] == "#" {
-            // println!("here right no dot {right:?}");
+            // eprintln!("here right no dot {right:?}");
             // return check(str[1..].to_owned(), n);
             memo.insert((str, n), 0);
             return 0;
         }
 
-        // println!("here taken '{right:?}'");
+        // eprintln!("here taken '{right:?}'");
         let taken_count = check(right[1..].to_owned(), &n[1..], memo);
-        // println!("here not");
+        // eprintln!("here not");
         // let not_taken_count = check(str[1..].to_owned(), n);
         memo.insert((str, n), taken_count);
         return taken_count;
     }
 
-    // println!("hmm forgotten: {:?} and {:?}", str, n);
+    // eprintln!("hmm forgotten: {:?} and {:?}", str, n);
     memo.insert((str, n), 0);
     0
 }
@@ -193,7 +191,7 @@ impl ExpandedSprings {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
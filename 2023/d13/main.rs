@@ -1,18 +1,14 @@
-use std::time::Instant;
-
 use aoclib::{
     grid::{FromChar, Grid},
     input,
 };
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day13>();
 }
 
+aoclib::solution!(Day13, 2023, 13);
+
 fn part1(txt: &str) -> usize {
     input::empty_line_chunks(txt)
         .map(|c| c.parse::<Grid<Tile>>().unwrap())
@@ -61,26 +57,26 @@ impl FromChar for Tile {
 }
 
 fn score(g: &Grid<Tile>, diffs: usize) -> usize {
-    let row = symmetry_index(&g.rows, diffs).unwrap_or(0);
-    let col = symmetry_index(&g.transpose().rows, diffs).unwrap_or(0);
+    let row = symmetry_index(g, diffs).unwrap_or(0);
+    let col = symmetry_index(&g.transpose(), diffs).unwrap_or(0);
 
     row * 100 + col
 }
 
-fn symmetry_index(items: &Vec<Vec<Tile>>, allowed: usize) -> Option<usize> {
-    for i in 0..(items.len() - 1) {
+fn symmetry_index(items: &Grid<Tile>, allowed: usize) -> Option<usize> {
+    for i in 0..(items.height() - 1) {
         let mut left_index = i as i32;
         let mut right_index = i as i32 + 1;
 
         let mut diffs = 0;
         loop {
-            if left_index < 0 || right_index >= items.len() as i32 {
+            if left_index < 0 || right_index >= items.height() as i32 {
                 // out of bounds
                 break;
             }
 
-            let left_items = &items[left_index as usize];
-            let right_items = &items[right_index as usize];
+            let left_items = items.row(left_index as usize);
+            let right_items = items.row(right_index as usize);
 
             diffs += left_items
                 .iter()
@@ -108,7 +104,7 @@ fn symmetry_index(items: &Vec<Vec<Tile>>, allowed: usize) -> Option<usize> {
 mod tests {
     use aoclib::input;
 
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
@@ -125,16 +121,16 @@ mod tests {
         let input: Vec<_> = input::empty_line_chunks(include_str!("input.test.txt")).collect();
         let g = input[0].parse::<Grid<Tile>>().unwrap();
 
-        for row in g.rows.iter() {
+        for row in g.rows() {
             for col in row {
-                print!("{}", col);
+                eprint!("{}", col);
             }
-            println!("");
+            eprintln!("");
         }
 
-        let i = symmetry_index(&g.rows, 0);
+        let i = symmetry_index(&g, 0);
         assert_eq!(None, i);
-        let i = symmetry_index(&g.transpose().rows, 0);
+        let i = symmetry_index(&g.transpose(), 0);
         assert_eq!(Some(5), i);
     }
 
@@ -143,9 +139,9 @@ mod tests {
         let input: Vec<_> = input::empty_line_chunks(include_str!("input.test.txt")).collect();
         let g = input[1].parse::<Grid<Tile>>().unwrap();
 
-        let i = symmetry_index(&g.rows, 0);
+        let i = symmetry_index(&g, 0);
         assert_eq!(Some(4), i);
-        let i = symmetry_index(&g.transpose().rows, 0);
+        let i = symmetry_index(&g.transpose(), 0);
         assert_eq!(None, i);
     }
 
@@ -154,9 +150,9 @@ mod tests {
         let input: Vec<_> = input::empty_line_chunks(include_str!("input.test.txt")).collect();
         let g = input[0].parse::<Grid<Tile>>().unwrap();
 
-        let i = symmetry_index(&g.rows, 1);
+        let i = symmetry_index(&g, 1);
         assert_eq!(Some(3), i);
-        let i = symmetry_index(&g.transpose().rows, 1);
+        let i = symmetry_index(&g.transpose(), 1);
         assert_eq!(None, i);
     }
 }
@@ -3,22 +3,19 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
     str::FromStr,
-    time::Instant,
 };
 
 use aoclib::{
     cartesian::{Plane, Point, Transform},
-    grid::Grid,
+    grid::{Grid, GridPosition},
 };
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day14>();
 }
 
+aoclib::solution!(Day14, 2023, 14);
+
 fn part1(txt: &str) -> i64 {
     let d: Dish = txt.parse().unwrap();
     tilt(d, &Tilt::North).score()
@@ -139,7 +136,7 @@ impl FromStr for Dish {
         for row in 0..height {
             let y = height - row - 1;
             for x in 0..width {
-                let c = grid.rows[row][x];
+                let c = grid[GridPosition::new(x, row)];
                 match c {
                     'O' => {
                         round.insert((x as i64, y as i64).into());
@@ -245,7 +242,7 @@ fn apply_tilt(d: &Dish, p: &Point, tilt: &Tilt, moved: &mut HashSet<Point>) {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
@@ -290,8 +287,8 @@ mod tests {
             .map(|rock| rock.y + 1)
             .collect();
         counts.sort();
-        println!("counts\n{:?}", counts);
-        println!("{}", counts.iter().sum::<i64>());
+        eprintln!("counts\n{:?}", counts);
+        eprintln!("{}", counts.iter().sum::<i64>());
     }
 
     #[test]
@@ -337,14 +334,14 @@ mod tests {
             for x in 0..width {
                 let p: Point = (x as i64, y as i64).into();
                 if d.round.contains(&p) {
-                    print!("O");
+                    eprint!("O");
                 } else if d.square.contains(&p) {
-                    print!("#");
+                    eprint!("#");
                 } else {
-                    print!(".");
+                    eprint!(".");
                 }
             }
-            println!("");
+            eprintln!("");
         }
     }
 }
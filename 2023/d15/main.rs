@@ -2,19 +2,16 @@ use std::{
     collections::HashMap,
     fmt::{Debug, Display},
     str::FromStr,
-    time::Instant,
 };
 
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day15>();
 }
 
+aoclib::solution!(Day15, 2023, 15);
+
 fn part1(txt: &str) -> u64 {
     txt.split(",")
         .map(|chunk| chunk.parse::<Operation>().unwrap())
@@ -168,7 +165,7 @@ impl FromStr for Operation {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
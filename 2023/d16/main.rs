@@ -3,22 +3,19 @@ use std::{
     collections::HashSet,
     fmt::{Debug, Display},
     str::FromStr,
-    time::Instant,
 };
 
 use aoclib::{
     cartesian::Point,
-    grid::{Flip, FromChar, Grid},
+    grid::{Flip, FromChar, Grid, GridPosition},
 };
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day16>();
 }
 
+aoclib::solution!(Day16, 2023, 16);
+
 fn part1(txt: &str) -> usize {
     let c: Contraption = txt.parse().unwrap();
     let start_point = (0, c.tiles.height() as i64 - 1).into();
@@ -142,8 +139,8 @@ impl FromStr for Contraption {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.parse::<Grid<Tile>>()
-            .map(|g| Contraption { tiles: g.flip() })
+        let g: Grid<Tile> = s.parse()?;
+        Ok(Contraption { tiles: g.flip() })
     }
 }
 
@@ -193,7 +190,8 @@ fn step(
         return;
     }
 
-    let current_tile = &contraption.tiles.rows[current.y as usize][current.x as usize];
+    let current_tile =
+        &contraption.tiles[GridPosition::new(current.x as usize, current.y as usize)];
     points.insert(current.clone());
 
     match current_tile {
@@ -269,7 +267,7 @@ fn step(
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
@@ -285,17 +283,17 @@ mod tests {
     fn test_parse() {
         let txt = include_str!("input.test.txt");
         let c: Grid<char> = txt.parse().unwrap();
-        println!("{}", c);
+        eprintln!("{}", c);
 
         let c: Contraption = txt.parse().unwrap();
-        println!("");
-        println!("{}", c.tiles);
+        eprintln!("");
+        eprintln!("{}", c.tiles);
 
-        println!("{:?}", c.tiles.rows[9]);
+        eprintln!("{:?}", c.tiles.row(9));
 
-        assert_eq!(Tile::Space, c.tiles.rows[9][0]);
-        assert_eq!(Tile::UpDown, c.tiles.rows[9][1]);
-        assert_eq!(Tile::LeftRight, c.tiles.rows[2][1]);
+        assert_eq!(Tile::Space, c.tiles[GridPosition::new(0, 9)]);
+        assert_eq!(Tile::UpDown, c.tiles[GridPosition::new(1, 9)]);
+        assert_eq!(Tile::LeftRight, c.tiles[GridPosition::new(1, 2)]);
     }
 
     #[test]
@@ -303,7 +301,7 @@ mod tests {
         let txt = include_str!("input.test.txt");
         let c: Contraption = txt.parse().unwrap();
 
-        println!("Contraption:\n{}", c.tiles.flip());
+        eprintln!("Contraption:\n{}", c.tiles.flip());
 
         let start_point = (0, c.tiles.height() as i64 - 1).into();
         let mut points = HashSet::new();
@@ -1,18 +1,14 @@
-use std::time::Instant;
-
 use aoclib::{
     grid::{Grid, GridPosition},
     shortest_path::{self, Heuristic, ManhattenDistanceTo},
 };
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day17>();
 }
 
+aoclib::solution!(Day17, 2023, 17);
+
 fn part1(txt: &str) -> usize {
     solve(txt, 1, 3)
 }
@@ -41,21 +37,21 @@ fn solve(txt: &str, min: usize, max: usize) -> usize {
     //             .find(|(s, _)| s.grid_pos == GridPosition::new(col, row))
     //         {
     //             Some((s, _)) => match s.direction {
-    //                 Direction::Up => print!("^"),
-    //                 Direction::Down => print!("v"),
-    //                 Direction::Left => print!("<"),
-    //                 Direction::Right => print!(">"),
+    //                 Direction::Up => eprint!("^"),
+    //                 Direction::Down => eprint!("v"),
+    //                 Direction::Left => eprint!("<"),
+    //                 Direction::Right => eprint!(">"),
     //             },
     //             None => {
-    //                 print!(".")
+    //                 eprint!(".")
     //             }
     //         }
     //     }
-    //     println!("");
+    //     eprintln!("");
     // }
 
     // for (p, c) in &result.path {
-    //     println!("{:?} = {}", p.grid_pos, c);
+    //     eprintln!("{:?} = {}", p.grid_pos, c);
     // }
 
     result.total_cost
@@ -239,7 +235,7 @@ impl shortest_path::Cost<State, usize> for LavaFall {
 mod tests {
     use aoclib::shortest_path::Cost;
 
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
@@ -248,10 +244,13 @@ mod tests {
 
     #[test]
     fn test_regression() {
+        let Some(input) = aoclib::input::load_or_skip(2023, 17) else {
+            return;
+        };
         // fails :( -> should be 1023
-        assert_eq!(1024, part1(include_str!("input.txt")));
+        assert_eq!(1024, part1(&input));
         // but pt2 works
-        assert_eq!(1165, part2(include_str!("input.txt")));
+        assert_eq!(1165, part2(&input));
     }
 
     #[test]
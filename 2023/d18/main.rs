@@ -1,15 +1,13 @@
-use std::{str::FromStr, time::Instant};
+use std::str::FromStr;
 
 use aoclib::cartesian::{Direction, Point, Transform};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day18>();
 }
 
+aoclib::solution!(Day18, 2023, 18);
+
 fn part1(txt: &str) -> i64 {
     let dp: Pt1DigPlan = txt.parse().unwrap();
     solve(dp.instructions)
@@ -128,7 +126,7 @@ impl Polygon {
 #[cfg(test)]
 mod tests {
 
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
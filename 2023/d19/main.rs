@@ -1,16 +1,14 @@
-use std::{collections::HashMap, str::FromStr, time::Instant};
+use std::{collections::HashMap, str::FromStr};
 
 use aoclib::input;
 use aoclib::range::*;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day19>();
 }
 
+aoclib::solution!(Day19, 2023, 19);
+
 fn part1(txt: &str) -> usize {
     let sections: Vec<_> = input::empty_line_chunks(txt).collect();
 
@@ -305,7 +303,7 @@ impl FromStr for Rating {
 #[cfg(test)]
 mod tests {
 
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
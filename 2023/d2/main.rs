@@ -1,15 +1,13 @@
-use std::{collections::HashMap, str::FromStr, time::Instant};
+use std::{collections::HashMap, str::FromStr};
 
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day2>();
 }
 
+aoclib::solution!(Day2, 2023, 2);
+
 fn part1(txt: &str) -> i32 {
     let bag = {
         let mut cubes = HashMap::new();
@@ -103,7 +101,7 @@ impl Bag {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
@@ -1,19 +1,16 @@
 use std::{
     collections::{HashMap, HashSet, VecDeque},
     str::FromStr,
-    time::Instant,
 };
 
 use aoclib::number;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day20>();
 }
 
+aoclib::solution!(Day20, 2023, 20);
+
 fn part1(txt: &str) -> usize {
     let mut module_config: ModuleConfig = txt.parse().expect("valid module config");
 
@@ -350,7 +347,7 @@ impl FromStr for Module {
 #[cfg(test)]
 mod tests {
 
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
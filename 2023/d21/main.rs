@@ -1,23 +1,20 @@
 use std::{
     collections::HashSet,
     fmt::{Display, Write},
-    time::Instant,
 };
 
 use aoclib::{
     cartesian::{Point, Transform},
-    grid::{Flip, FromChar, Grid, GridPosition},
+    grid::{Flip, FromChar, Grid},
 };
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day21>();
 }
 
+aoclib::solution!(Day21, 2023, 21);
+
 fn part1(txt: &str) -> usize {
     let g: Grid<_> = txt.parse().unwrap();
     let map = Map(g.flip());
@@ -66,7 +63,7 @@ fn part2(txt: &str) -> i64 {
         step_increment += increment;
         value += step_increment;
 
-        // println!("step {} = {}", step, value);
+        // eprintln!("step {} = {}", step, value);
 
         // have some off by ones somewhere
         if step == target_step + 1 {
@@ -88,28 +85,10 @@ fn grids_to_count(map: &Map, grid: usize) -> usize {
 #[derive(Debug, Clone)]
 struct Map(Grid<Position>);
 
-fn mod_dimension(max: usize, dimension: i64) -> i64 {
-    dimension.rem_euclid(max as i64)
-}
-
 impl Map {
     fn at(&self, p: &Point) -> &Position {
-        let mod_col = mod_dimension(self.0.width(), p.x);
-        let mod_row = mod_dimension(self.0.height(), p.y);
-
-        if mod_col < 0
-            || mod_row < 0
-            || mod_col >= self.0.width() as i64
-            || mod_row >= self.0.height() as i64
-        {
-            panic!(
-                "{p:?} height {} modified to {mod_col},{mod_row}",
-                self.0.height()
-            );
-        }
-
-        self.0
-            .at(&GridPosition::new(mod_col as usize, mod_row as usize))
+        let (position, _tile) = self.0.wrapping().get(p);
+        position
     }
 
     fn start_pos(&self) -> Option<Point> {
@@ -194,6 +173,7 @@ impl Flip for Position {
 
 #[cfg(test)]
 mod tests {
+    use aoclib::grid::GridPosition;
 
     use super::*;
 
@@ -233,21 +213,26 @@ mod tests {
     }
 
     #[test]
-    fn test_mod_dimension_wraps_around() {
+    fn test_map_wraps_around() {
         let origin = include_str!("input.test.txt");
         let g_orig: Grid<Position> = origin.parse().unwrap();
         let m_orig = Map(g_orig.flip());
 
-        let width = m_orig.0.width();
-
-        let result = mod_dimension(width, -1);
-        assert_eq!(m_orig.0.width() as i64 - 1, result);
-
-        let result = mod_dimension(width, -11);
-        assert_eq!(0, result);
+        let tiled = m_orig.0.wrapping();
+        let last_col = m_orig.0.width() - 1;
 
-        let result = mod_dimension(width, -12);
-        assert_eq!(m_orig.0.width() as i64 - 1, result);
+        assert_eq!(
+            (GridPosition::new(last_col, 0), Point::new(-1, 0)),
+            tiled.locate(&Point::new(-1, 0))
+        );
+        assert_eq!(
+            (GridPosition::new(0, 0), Point::new(-1, 0)),
+            tiled.locate(&Point::new(-11, 0))
+        );
+        assert_eq!(
+            (GridPosition::new(last_col, 3), Point::new(-2, 0)),
+            tiled.locate(&Point::new(-12, 3))
+        );
     }
 
     #[test]
@@ -261,7 +246,7 @@ mod tests {
 
         assert_eq!(true, expanded_steps.contains(&Point::new(0, 5)));
 
-        println!(
+        eprintln!(
             "{:?}",
             m_orig.at(&Point::new(0, 5).transform(&Transform::left()))
         );
@@ -270,8 +255,8 @@ mod tests {
             m_orig.can_walk_to(&Point::new(0, 5).transform(&Transform::left()))
         );
 
-        println!();
-        println!();
+        eprintln!();
+        eprintln!();
 
         let expanded_steps = m_orig.steps(8);
         print_steps(&m_orig, &expanded_steps);
@@ -289,47 +274,47 @@ mod tests {
         let m_orig_expand = Map(g_orig_expand.flip());
         let expand_start = m_orig_expand.start_pos().unwrap();
 
-        println!("width: {}", m_orig.0.width());
+        eprintln!("width: {}", m_orig.0.width());
 
         for i in 0..10 {
             let expanded_steps = m_orig_expand.steps(i);
             let orig_steps = m_orig.steps(i);
 
             // if expanded_steps.len() != orig_steps.len() {
-            println!("expanded S = {:?}", m_orig_expand.start_pos().unwrap());
+            eprintln!("expanded S = {:?}", m_orig_expand.start_pos().unwrap());
             for y in 9..25 {
-                print!("{y}\t");
+                eprint!("{y}\t");
                 for x in 6..30 {
                     let p = (x as i64, y as i64).into();
                     if expand_start == p {
-                        print!("S");
+                        eprint!("S");
                     } else if expanded_steps.contains(&p) {
-                        print!("O");
+                        eprint!("O");
                     } else {
-                        print!(".");
+                        eprint!(".");
                     }
                 }
-                println!("");
+                eprintln!("");
             }
-            println!("origin S = {:?}", m_orig.start_pos().unwrap());
+            eprintln!("origin S = {:?}", m_orig.start_pos().unwrap());
             for y in -2..14 {
-                print!("{y}\t");
+                eprint!("{y}\t");
                 for x in -5..19 {
                     let p = (x as i64, y as i64).into();
                     if orig_start == p {
-                        print!("S");
+                        eprint!("S");
                     } else if orig_steps.contains(&p) {
-                        print!("O");
+                        eprint!("O");
                     } else {
-                        print!(".");
+                        eprint!(".");
                     }
                 }
-                println!("");
+                eprintln!("");
             }
             // }
-            println!("");
-            println!("");
-            println!("");
+            eprintln!("");
+            eprintln!("");
+            eprintln!("");
 
             assert_eq!(orig_steps.len(), expanded_steps.len(), "failed at {}", i);
         }
@@ -344,20 +329,20 @@ mod tests {
         let min_x = points.iter().map(|p| p.x).min().unwrap() - 5;
         let max_x = points.iter().map(|p| p.x).max().unwrap() + 5;
 
-        println!("steps {}", points.len());
+        eprintln!("steps {}", points.len());
         for y in min_y..max_y {
-            print!("{y}\t");
+            eprint!("{y}\t");
             for x in min_x..max_x {
                 let p = (x as i64, y as i64).into();
                 if start == p {
-                    print!("S");
+                    eprint!("S");
                 } else if points.contains(&p) {
-                    print!("O");
+                    eprint!("O");
                 } else {
-                    print!(".");
+                    eprint!(".");
                 }
             }
-            println!("");
+            eprintln!("");
         }
     }
 
@@ -382,8 +367,8 @@ mod tests {
         ];
 
         print_steps(&expanded_m, &expanded_m.steps(10));
-        println!();
-        println!();
+        eprintln!();
+        eprintln!();
         print_steps(&m, &m.steps(10));
 
         for (i, expected) in steps {
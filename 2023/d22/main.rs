@@ -1,16 +1,14 @@
-use std::{cmp::Ordering, str::FromStr, time::Instant};
+use std::{cmp::Ordering, str::FromStr};
 
 use aoclib::range::Range;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day22>();
 }
 
+aoclib::solution!(Day22, 2023, 22);
+
 fn part1(txt: &str) -> usize {
     let mut bricks: Vec<Brick> = txt.lines().map(|l| l.parse().unwrap()).sorted().collect();
     let collisions = drop_down(&mut bricks);
@@ -207,6 +205,6 @@ mod tests {
         assert_eq!(5, parsed[6].z().from());
         assert_eq!(7, parsed[6].z().to()); // + 1 of actual because of range bounds
 
-        println!("{:?}", col);
+        eprintln!("{:?}", col);
     }
 }
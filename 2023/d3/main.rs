@@ -1,7 +1,4 @@
-use std::{
-    collections::{HashMap, HashSet},
-    time::Instant,
-};
+use std::collections::{HashMap, HashSet};
 
 use aoclib::{
     cartesian::{Plane, Point},
@@ -11,13 +8,11 @@ use aoclib::{
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day3>();
 }
 
+aoclib::solution!(Day3, 2023, 3);
+
 fn is_symbol(c: char) -> bool {
     match c {
         '.' => false,
@@ -181,7 +176,7 @@ impl Number {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_left_and_right() {
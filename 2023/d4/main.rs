@@ -1,15 +1,13 @@
-use std::{collections::HashSet, str::FromStr, time::Instant};
+use std::{collections::HashSet, str::FromStr};
 
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day4>();
 }
 
+aoclib::solution!(Day4, 2023, 4);
+
 fn part1(txt: &str) -> i64 {
     txt.lines()
         .map(|l| l.parse::<Card>().unwrap())
@@ -82,7 +80,7 @@ impl FromStr for Card {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_points() {
@@ -1,16 +1,14 @@
-use std::{collections::HashMap, i64, str::FromStr, time::Instant};
+use std::{collections::HashMap, i64, str::FromStr};
 
 use aoclib::input;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day5>();
 }
 
+aoclib::solution!(Day5, 2023, 5);
+
 fn part1(txt: &str) -> i64 {
     let mut parts = input::empty_line_chunks(txt);
 
@@ -240,7 +238,8 @@ impl FromStr for Mapping {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_example_p1() {
@@ -340,11 +339,11 @@ mod tests {
         let r: Range = "45 77 23".parse().unwrap();
         assert_eq!(Some(45), r.resolve(77));
 
-        println!("***************");
+        eprintln!("***************");
         for m in &mappings {
-            println!("{:?}", m);
+            eprintln!("{:?}", m);
         }
-        println!("***************");
+        eprintln!("***************");
 
         assert_eq!("light", mappings[4].from);
         assert_eq!("temperature", mappings[4].to);
@@ -353,14 +352,14 @@ mod tests {
         let mut i = 82;
         for m in mappings {
             let next = m.resolve(i);
-            println!("from {} {} to {} {}", m.from, i, m.to, next);
+            eprintln!("from {} {} to {} {}", m.from, i, m.to, next);
             i = next;
         }
     }
 
     #[test]
     fn test_regression() {
-        assert_eq!(309796150, part1(include_str!("input.txt")));
-        assert_eq!(50716416, part2(include_str!("input.txt")));
+        aoclib::answers::assert_correct::<Day5>(Part::One);
+        aoclib::answers::assert_correct::<Day5>(Part::Two);
     }
 }
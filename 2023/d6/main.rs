@@ -1,16 +1,14 @@
-use std::{str::FromStr, time::Instant};
+use std::str::FromStr;
 
 use itertools::Itertools;
 use std::iter::zip;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day6>();
 }
 
+aoclib::solution!(Day6, 2023, 6);
+
 fn part1(txt: &str) -> i64 {
     let input: Part1Input = txt.parse().expect("input");
     input
@@ -106,7 +104,7 @@ impl FromStr for Part2Input {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
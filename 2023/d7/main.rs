@@ -1,15 +1,13 @@
-use std::{collections::HashMap, marker::PhantomData, str::FromStr, time::Instant};
+use std::{collections::HashMap, marker::PhantomData, str::FromStr};
 
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day7>();
 }
 
+aoclib::solution!(Day7, 2023, 7);
+
 fn part1(txt: &str) -> i64 {
     txt.lines()
         .map(|l| l.parse::<Hand<StandardOrdering>>().unwrap())
@@ -247,7 +245,8 @@ impl<T: CardOrdering> FromStr for Hand<T> {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_example_p1() {
@@ -329,9 +328,9 @@ mod tests {
 
         parsed.sort();
 
-        println!("cards");
+        eprintln!("cards");
         for c in &parsed {
-            println!("{:?}", c);
+            eprintln!("{:?}", c);
         }
 
         assert_eq!(
@@ -348,6 +347,6 @@ mod tests {
 
     #[test]
     fn test_regression() {
-        assert_eq!(250120186, part1(include_str!("input.txt")));
+        aoclib::answers::assert_correct::<Day7>(Part::One);
     }
 }
@@ -1,15 +1,13 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
 use aoclib::{input, number};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day8>();
 }
 
+aoclib::solution!(Day8, 2023, 8);
+
 fn part1(txt: &str) -> usize {
     let parts: Vec<_> = input::empty_line_chunks(txt).collect();
     let directions: Vec<_> = parts[0].chars().collect();
@@ -88,14 +86,14 @@ fn part2(txt: &str) -> u64 {
             }
         }
     }
-    println!("solved {:?}", steps);
+    eprintln!("solved {:?}", steps);
 
     number::lcm(&steps[0..])
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
@@ -1,14 +1,12 @@
 use itertools::Itertools;
-use std::{i64, str::FromStr, time::Instant};
+use std::{i64, str::FromStr};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}ms", now.elapsed().as_millis());
+    aoclib::solution::main::<Day9>();
 }
 
+aoclib::solution!(Day9, 2023, 9);
+
 fn part1(txt: &str) -> i64 {
     txt.lines()
         .map(|l| l.parse::<ReportEntry>().unwrap())
@@ -50,7 +48,7 @@ impl ReportEntry {
             if row.iter().all(|&i| i == 0) {
                 break;
             } else {
-                // println!("{:?}", row);
+                // eprintln!("{:?}", row);
             }
         }
 
@@ -85,15 +83,15 @@ impl ReportEntry {
             if row.iter().all(|&i| i == 0) {
                 break;
             } else {
-                // println!("{:?}", row);
+                // eprintln!("{:?}", row);
             }
         }
 
         let mut increment = 0;
         while let Some(next) = down.pop() {
-            // println!("{:?}", next);
+            // eprintln!("{:?}", next);
             increment = next - increment;
-            // println!("{:?}\n", increment);
+            // eprintln!("{:?}\n", increment);
         }
 
         increment
@@ -114,7 +112,7 @@ impl FromStr for ReportEntry {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_example_p1() {
@@ -1,18 +1,13 @@
-use std::{iter::zip, time::Instant};
+use std::iter::zip;
 
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day1>();
 }
 
+aoclib::solution!(Day1, 2024, 1);
+
 fn part1(txt: &str) -> usize {
     let mut left: Vec<usize> = Vec::new();
     let mut right: Vec<usize> = Vec::new();
@@ -49,7 +44,8 @@ fn part2(txt: &str) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn sample_input_pt1() {
@@ -59,8 +55,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1830467, part1(test_input));
+        aoclib::answers::assert_correct::<Day1>(Part::One);
     }
 
     #[test]
@@ -71,7 +66,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(26674158, part2(test_input));
+        aoclib::answers::assert_correct::<Day1>(Part::Two);
     }
 }
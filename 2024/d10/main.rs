@@ -1,20 +1,15 @@
 use core::str;
-use std::{time::Instant, usize};
+use std::usize;
 
-use aoclib::{
-    grid::{Grid, GridPosition},
-    timing,
-};
+use aoclib::grid::{Grid, GridPosition};
 use hashbrown::{HashMap, HashSet};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day10>();
 }
 
+aoclib::solution!(Day10, 2024, 10);
+
 fn part1(txt: &str) -> usize {
     let g: Grid<usize> = txt.parse().unwrap();
     let mut starts = HashSet::new();
@@ -126,9 +121,12 @@ fn next(p: GridPosition, g: &Grid<usize>, path: &mut Vec<GridPosition>) -> Vec<V
 
 #[cfg(test)]
 mod tests {
-    use aoclib::shortest_path::{Neighbours, NonDiagonalNeighbours};
+    use aoclib::{
+        shortest_path::{Neighbours, NonDiagonalNeighbours},
+        solution::Part,
+    };
 
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_input_pt1_simpl_neighbours() {
@@ -148,8 +146,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(746, part1(test_input));
+        aoclib::answers::assert_correct::<Day10>(Part::One);
     }
 
     #[test]
@@ -160,7 +157,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1541, part2(test_input));
+        aoclib::answers::assert_correct::<Day10>(Part::Two);
     }
 }
@@ -1,18 +1,15 @@
 use core::str;
-use std::{time::Instant, usize};
+use std::usize;
 
-use aoclib::timing;
 use hashbrown::HashMap;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day11>();
 }
 
+aoclib::solution!(Day11, 2024, 11);
+
 fn part1(txt: &str) -> usize {
     let stones = txt
         .split_whitespace()
@@ -113,7 +110,7 @@ fn count_n(
 mod tests {
     use itertools::Itertools;
 
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_digits() {
@@ -129,14 +126,16 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
+        let Some(test_input) = aoclib::input::load_or_skip(2024, 11) else {
+            return;
+        };
         let stones = test_input
             .split_whitespace()
             .map(|n| n.parse::<usize>().unwrap())
             .map(Stone)
             .collect_vec();
 
-        assert_eq!(233875, part1(test_input));
+        assert_eq!(233875, part1(&test_input));
         assert_eq!(233875, count_all(25, stones));
     }
 
@@ -155,7 +154,9 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
+        let Some(test_input) = aoclib::input::load_or_skip(2024, 11) else {
+            return;
+        };
         let stones = test_input
             .split_whitespace()
             .map(|n| n.parse::<usize>().unwrap())
@@ -1,21 +1,13 @@
-use core::str;
-use std::{str::FromStr, time::Instant, usize};
+use std::str::FromStr;
 
-use aoclib::{
-    grid::{FromChar, Grid, GridPosition},
-    timing,
-};
-use hashbrown::{HashMap, HashSet};
-use itertools::Itertools;
+use aoclib::grid::{FromChar, Grid, Regions};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day12>();
 }
 
+aoclib::solution!(Day12, 2024, 12);
+
 fn part1(txt: &str) -> usize {
     let garden: Garden = txt.parse().unwrap();
 
@@ -29,15 +21,15 @@ fn part2(txt: &str) -> usize {
 }
 
 #[derive(Debug, Clone)]
-struct Garden(Grid<Plant>, Vec<Plot>);
+struct Garden(Regions);
 
 impl Garden {
     fn total_price(&self) -> usize {
-        self.1.iter().map(|plot| plot.price(&self.0)).sum()
+        self.0.iter().map(|plot| plot.area() * plot.perimeter()).sum()
     }
 
     fn total_discount_price(&self) -> usize {
-        self.1.iter().map(|plot| plot.discount_price(&self.0)).sum()
+        self.0.iter().map(|plot| plot.area() * plot.sides()).sum()
     }
 }
 
@@ -45,232 +37,14 @@ impl FromStr for Garden {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let g: Grid<Plant> = s.parse().unwrap();
-
-        let mut all_positions = HashSet::new();
-        for row in 0..g.height() {
-            for col in 0..g.width() {
-                let p = GridPosition::new(col, row);
-                all_positions.insert(p);
-            }
-        }
-
-        let mut seen = HashSet::new();
-        let mut plots: Vec<Plot> = Vec::new();
-        for pos in all_positions {
-            if seen.contains(&pos) {
-                continue;
-            }
-            let p = g.at(&pos);
-            let mut grouping = HashSet::new();
-            flood_fill(p, &pos, &g, &mut grouping);
-            seen.extend(grouping.clone());
-            plots.push(Plot {
-                positions: grouping,
-            });
-        }
-
-        Ok(Garden(g, plots))
+        let g: Grid<Plant> = s.parse()?;
+        Ok(Garden(g.components(|a, b| a == b)))
     }
 }
 
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
 struct Plant(char);
 
-#[derive(Debug, Clone)]
-struct Plot {
-    positions: HashSet<GridPosition>,
-}
-
-impl Plot {
-    fn perimeter(&self, g: &Grid<Plant>) -> usize {
-        let mut total = 0;
-        for p in &self.positions {
-            let plant = g.at(p);
-
-            // up
-            if p.row == 0 {
-                total += 1;
-            } else {
-                let up = g.at(&p.up());
-                if up != plant {
-                    total += 1;
-                }
-            }
-
-            // down
-            if p.row == g.height() - 1 {
-                total += 1;
-            } else {
-                let down = g.at(&p.down());
-                if down != plant {
-                    total += 1;
-                }
-            }
-
-            // left
-            if p.col == 0 {
-                total += 1;
-            } else {
-                let left = g.at(&p.left());
-                if left != plant {
-                    total += 1;
-                }
-            }
-
-            // right
-            if p.col == g.width() - 1 {
-                total += 1;
-            } else {
-                let right = g.at(&p.right());
-                if right != plant {
-                    total += 1;
-                }
-            }
-        }
-        total
-    }
-
-    fn sides(&self, g: &Grid<Plant>) -> usize {
-        let mut left_sides = HashSet::new();
-        let mut top_sides = HashSet::new();
-        let mut bottom_sides = HashSet::new();
-        let mut right_sides = HashSet::new();
-
-        for p in &self.positions {
-            if p.row == 0 {
-                top_sides.insert(p.clone());
-            } else {
-                if !&self.positions.contains(&p.up()) {
-                    top_sides.insert(p.clone());
-                }
-            }
-            if p.col == 0 {
-                left_sides.insert(p.clone());
-            } else {
-                if !&self.positions.contains(&p.left()) {
-                    left_sides.insert(p.clone());
-                }
-            }
-            if p.row == g.height() - 1 {
-                bottom_sides.insert(p.clone());
-            } else {
-                if !&self.positions.contains(&p.down()) {
-                    bottom_sides.insert(p.clone());
-                }
-            }
-            if p.col == g.width() - 1 {
-                right_sides.insert(p.clone());
-            } else {
-                if !&self.positions.contains(&p.right()) {
-                    right_sides.insert(p.clone());
-                }
-            }
-        }
-
-        let mut tops: HashMap<usize, Vec<GridPosition>> = HashMap::new();
-        for p in top_sides {
-            match tops.get_mut(&p.row) {
-                Some(existing) => {
-                    existing.push(p.clone());
-                }
-                _ => {
-                    tops.insert(p.row, vec![p.clone()]);
-                }
-            }
-        }
-        let mut bottoms: HashMap<usize, Vec<GridPosition>> = HashMap::new();
-        for p in bottom_sides {
-            match bottoms.get_mut(&p.row) {
-                Some(existing) => {
-                    existing.push(p.clone());
-                }
-                _ => {
-                    bottoms.insert(p.row, vec![p.clone()]);
-                }
-            }
-        }
-
-        let mut hs = 0;
-        let mut h_gaps = 0;
-        for (_k, mut v) in tops {
-            v.sort_by(|a, b| a.col.cmp(&b.col));
-            hs += 1;
-            for (left, right) in v.iter().tuple_windows() {
-                if &left.right() != right {
-                    h_gaps += 1;
-                }
-            }
-        }
-        for (_k, mut v) in bottoms {
-            v.sort_by(|a, b| a.col.cmp(&b.col));
-            hs += 1;
-            for (left, right) in v.iter().tuple_windows() {
-                if &left.right() != right {
-                    h_gaps += 1;
-                }
-            }
-        }
-
-        let mut lefts: HashMap<usize, Vec<GridPosition>> = HashMap::new();
-        for p in left_sides {
-            match lefts.get_mut(&p.col) {
-                Some(existing) => {
-                    existing.push(p.clone());
-                }
-                _ => {
-                    lefts.insert(p.col, vec![p.clone()]);
-                }
-            }
-        }
-        let mut rights: HashMap<usize, Vec<GridPosition>> = HashMap::new();
-        for p in right_sides {
-            match rights.get_mut(&p.col) {
-                Some(existing) => {
-                    existing.push(p.clone());
-                }
-                _ => {
-                    rights.insert(p.col, vec![p.clone()]);
-                }
-            }
-        }
-
-        let mut vs = 0;
-        let mut v_gaps = 0;
-        for (_k, mut v) in lefts {
-            v.sort_by(|a, b| a.row.cmp(&b.row));
-            vs += 1;
-            for (top, bottom) in v.iter().tuple_windows() {
-                if &top.down() != bottom {
-                    v_gaps += 1;
-                }
-            }
-        }
-        for (_k, mut v) in rights {
-            v.sort_by(|a, b| a.row.cmp(&b.row));
-            vs += 1;
-            for (top, bottom) in v.iter().tuple_windows() {
-                if &top.down() != bottom {
-                    v_gaps += 1;
-                }
-            }
-        }
-        hs + h_gaps + vs + v_gaps
-    }
-
-    fn area(&self) -> usize {
-        self.positions.len()
-    }
-
-    fn price(&self, g: &Grid<Plant>) -> usize {
-        self.area() * self.perimeter(g)
-    }
-
-    fn discount_price(&self, g: &Grid<Plant>) -> usize {
-        self.area() * self.sides(g)
-    }
-}
-
 impl FromChar for Plant {
     type Err = String;
 
@@ -279,44 +53,10 @@ impl FromChar for Plant {
     }
 }
 
-fn flood_fill(
-    plant_type: &Plant,
-    pos: &GridPosition,
-    grid: &Grid<Plant>,
-    seen: &mut HashSet<GridPosition>,
-) {
-    if seen.contains(pos) {
-        return;
-    }
-
-    let plant = grid.at(pos);
-    if plant != plant_type {
-        return;
-    }
-
-    seen.insert(pos.clone());
-
-    if pos.col > 0 {
-        let left_pos = pos.left();
-        flood_fill(plant_type, &left_pos, grid, seen);
-    }
-    if pos.col < grid.width() - 1 {
-        let right_pos = pos.right();
-        flood_fill(plant_type, &right_pos, grid, seen);
-    }
-    if pos.row > 0 {
-        let up_pos = pos.up();
-        flood_fill(plant_type, &up_pos, grid, seen);
-    }
-    if pos.row < grid.height() - 1 {
-        let up_pos = pos.down();
-        flood_fill(plant_type, &up_pos, grid, seen);
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -327,8 +67,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1415378, part1(test_input));
+        aoclib::answers::assert_correct::<Day12>(Part::One);
     }
 
     #[test]
@@ -345,7 +84,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(862714, part2(test_input));
+        aoclib::answers::assert_correct::<Day12>(Part::Two);
     }
 }
@@ -1,17 +1,15 @@
 use core::str;
-use std::{i64, str::FromStr, time::Instant};
+use std::{i64, str::FromStr};
 
-use aoclib::{input, timing};
+use aoclib::input;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day13>();
 }
 
+aoclib::solution!(Day13, 2024, 13);
+
 fn part1(txt: &str) -> i64 {
     input::empty_line_chunks(txt)
         .map(|chunk| chunk.parse::<ClawMachine>().unwrap())
@@ -122,7 +120,8 @@ impl FromStr for ClawMachine {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_multiples() {
@@ -140,7 +139,7 @@ mod tests {
             Prize { x: 8400, y: 5400 },
         );
         let result = c.solve();
-        println!("result: {:?}", result);
+        eprintln!("result: {:?}", result);
     }
 
     #[test]
@@ -151,13 +150,11 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(31761, part1(test_input));
+        aoclib::answers::assert_correct::<Day13>(Part::One);
     }
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(90798500745591, part2(test_input));
+        aoclib::answers::assert_correct::<Day13>(Part::Two);
     }
 }
@@ -1,21 +1,19 @@
 use core::str;
-use std::{i64, str::FromStr, time::Instant, usize};
+use std::{i64, io, ops::Range, str::FromStr, usize};
 
 use aoclib::{
     cartesian::{Plane, Point, Transform},
-    timing,
+    render::{Format, Frames, Image, Rgb},
 };
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day14>();
 }
 
+aoclib::solution!(Day14, 2024, 14);
+
 fn part1(txt: &str) -> i64 {
     let robots = txt.lines().map(|l| l.parse::<Robot>().unwrap());
     let b = Bathroom::new(101, 103);
@@ -61,8 +59,9 @@ fn part2(txt: &str) -> i64 {
         .max_by(|(left_s, left), (right_s, right)| left.cmp(right).then(right_s.cmp(left_s)))
         .unwrap();
 
-    // println!("{} at {}s", most_intersections, seconds);
+    // eprintln!("{} at {}s", most_intersections, seconds);
     // print_bathroom(&b, &robots.iter().map(|robot| robot.simulate(seconds, &b)).collect());
+    // save_frames(&b, &robots, 1..10000, "frames").unwrap();
     seconds
 }
 
@@ -113,9 +112,9 @@ impl Bathroom {
                     let y_quandrant = (y / ((self.0.height() + 1) / 2)) as usize;
                     // let quadrant = (x_quandrant + y_quandrant) as usize;
 
-                    // println!("x {} -> Qx {} w {}", x, x_quandrant, self.0.width());
-                    // println!("y {} -> Qy {} h {}", y, y_quandrant, self.0.height());
-                    // println!("{},{} = Q{}\n", x, y, quadrant);
+                    // eprintln!("x {} -> Qx {} w {}", x, x_quandrant, self.0.width());
+                    // eprintln!("y {} -> Qy {} h {}", y, y_quandrant, self.0.height());
+                    // eprintln!("{},{} = Q{}\n", x, y, quadrant);
 
                     let pos: Point = (x, y).into();
                     if let Some(robots_here) = robot_positions.get(&pos) {
@@ -125,7 +124,7 @@ impl Bathroom {
             }
         }
 
-        // println!("Quads: {:?}", quadrants);
+        // eprintln!("Quads: {:?}", quadrants);
 
         quadrants[0][0] * quadrants[0][1] * quadrants[1][0] * quadrants[1][1]
     }
@@ -174,20 +173,44 @@ fn print_bathroom(b: &Bathroom, r: &Vec<Robot>) {
             let p = Point::new(x, y);
             match placed.get(&p) {
                 Some(here) => {
-                    print!("{}", here.len());
+                    eprint!("{}", here.len());
                 }
                 _ => {
-                    print!(".");
+                    eprint!(".");
                 }
             }
         }
-        println!("");
+        eprintln!("");
+    }
+}
+
+/// A picture of the robots for each of `seconds`, to flick through looking
+/// for the tree
+#[allow(dead_code)]
+fn save_frames(b: &Bathroom, robots: &[Robot], seconds: Range<i64>, dir: &str) -> io::Result<()> {
+    let mut frames = Frames::new(dir, Format::Ppm)?;
+    for s in seconds {
+        let sim = robots
+            .iter()
+            .map(|robot| robot.simulate(s, b))
+            .collect_vec();
+        let placed = b.place(&sim);
+        let image = Image::from_plane(&b.0, |p| {
+            if placed.contains_key(p) {
+                Rgb::GREEN
+            } else {
+                Rgb::BLACK
+            }
+        });
+        frames.save(&image)?;
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_robot_movement() {
@@ -219,13 +242,11 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(230686500, part1(test_input));
+        aoclib::answers::assert_correct::<Day14>(Part::One);
     }
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(7672, part2(test_input));
+        aoclib::answers::assert_correct::<Day14>(Part::Two);
     }
 }
@@ -1,19 +1,17 @@
 use core::str;
-use std::{fmt::Display, str::FromStr, time::Instant, usize};
+use std::{fmt::Display, str::FromStr, usize};
 
 use aoclib::{
     grid::{FromChar, Grid, GridPosition},
-    input, timing,
+    input,
 };
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day15>();
 }
 
+aoclib::solution!(Day15, 2024, 15);
+
 fn part1(txt: &str) -> usize {
     let mut puzzle: Puzzle = txt.parse().unwrap();
     puzzle.apply_moves();
@@ -69,13 +67,13 @@ impl Puzzle {
         let mut robot = find_one(Tile::Robot, &self.map);
         for i in 0..self.directions.len() {
             let d = self.directions[i].clone();
-            // println!("Move {}:", d);
+            // eprintln!("Move {}:", d);
             let moved = self.apply_move(&robot, &d);
             if let Some(m) = moved {
                 robot = m;
             }
-            // println!("{}", self.map);
-            // println!("robot at {:?}\n\n", robot);
+            // eprintln!("{}", self.map);
+            // eprintln!("robot at {:?}\n\n", robot);
         }
     }
 
@@ -83,7 +81,7 @@ impl Puzzle {
         let next_pos = d.apply(p);
         let next_tile = self.map.at(&next_pos);
 
-        // println!("next tile after {} is {}", d, next_tile);
+        // eprintln!("next tile after {} is {}", d, next_tile);
 
         let was_moved = match next_tile {
             Tile::Wall | Tile::Robot => return None,
@@ -96,8 +94,8 @@ impl Puzzle {
         if let Some(_moved) = was_moved {
             // move current into freed space
             let tile_at_p = self.map.at(&p);
-            self.map.rows[next_pos.row][next_pos.col] = tile_at_p.clone();
-            self.map.rows[p.row][p.col] = Tile::Space;
+            *self.map.at_mut(&next_pos) = tile_at_p.clone();
+            *self.map.at_mut(p) = Tile::Space;
             Some(next_pos)
         } else {
             None
@@ -230,10 +228,9 @@ impl From<Puzzle> for Puzzle2 {
     fn from(value: Puzzle) -> Self {
         let rows = value
             .map
-            .rows
-            .into_iter()
+            .rows()
             .map(|row| {
-                row.into_iter()
+                row.iter()
                     .flat_map(|col| match col {
                         Tile::Wall => vec![Tile2::Wall, Tile2::Wall].into_iter(),
                         Tile::Box => vec![Tile2::LeftBox, Tile2::RightBox].into_iter(),
@@ -245,7 +242,7 @@ impl From<Puzzle> for Puzzle2 {
             .collect();
 
         Puzzle2 {
-            map: Grid { rows },
+            map: Grid::new(rows),
             directions: value.directions,
         }
     }
@@ -256,7 +253,7 @@ impl Puzzle2 {
         let mut robot = find_one(Tile2::Robot, &self.map);
         for i in 0..self.directions.len() {
             let d = &self.directions[i].clone();
-            // println!("Move {}:", d);
+            // eprintln!("Move {}:", d);
 
             match d {
                 Direction::Left | Direction::Right => {
@@ -264,15 +261,15 @@ impl Puzzle2 {
                     if let Some(moved) = moved {
                         robot = moved;
                     }
-                    // println!("{}", self.map);
-                    // println!("robot at {:?}\n\n", robot);
+                    // eprintln!("{}", self.map);
+                    // eprintln!("robot at {:?}\n\n", robot);
                 }
                 Direction::Up | Direction::Down => {
                     if self.can_move_vertically(robot, d.clone()) {
                         self.move_vertically(robot, d.clone());
                         robot = d.apply(&robot);
-                        // println!("{}", self.map);
-                        // println!("robot at {:?}\n\n", robot);
+                        // eprintln!("{}", self.map);
+                        // eprintln!("robot at {:?}\n\n", robot);
                     }
                 }
             }
@@ -283,7 +280,7 @@ impl Puzzle2 {
         let next_pos = d.apply(p);
         let next_tile = self.map.at(&next_pos);
 
-        // println!("next tile after {} is {}", d, next_tile);
+        // eprintln!("next tile after {} is {}", d, next_tile);
 
         let was_moved = match next_tile {
             Tile2::Wall | Tile2::Robot => return None,
@@ -296,8 +293,8 @@ impl Puzzle2 {
         if let Some(_moved) = was_moved {
             // move current into freed space
             let tile_at_p = self.map.at(&p);
-            self.map.rows[next_pos.row][next_pos.col] = tile_at_p.clone();
-            self.map.rows[p.row][p.col] = Tile2::Space;
+            *self.map.at_mut(&next_pos) = tile_at_p.clone();
+            *self.map.at_mut(p) = Tile2::Space;
             Some(next_pos)
         } else {
             None
@@ -344,20 +341,21 @@ impl Puzzle2 {
             Tile2::Wall => panic!("next tile is wall"),
         }
         let tile_at_p = self.map.at(&p);
-        self.map.rows[next_pos.row][next_pos.col] = tile_at_p.clone();
-        self.map.rows[p.row][p.col] = Tile2::Space;
+        *self.map.at_mut(&next_pos) = tile_at_p.clone();
+        *self.map.at_mut(&p) = Tile2::Space;
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1_parse() {
         let test_input = include_str!("input.test.small.txt");
         let mut puzzle: Puzzle = test_input.parse().unwrap();
-        // println!("{}", puzzle.map);
+        // eprintln!("{}", puzzle.map);
         puzzle.apply_moves();
         assert_eq!(2028, sum_of_gps(&Tile::Box, &puzzle.map));
     }
@@ -366,7 +364,7 @@ mod tests {
     fn test_gps_sum() {
         let test_input = include_str!("input.sum.txt");
         let puzzle: Puzzle = test_input.parse().unwrap();
-        // println!("{}", puzzle.map);
+        // eprintln!("{}", puzzle.map);
         assert_eq!(104, sum_of_gps(&Tile::Box, &puzzle.map));
     }
 
@@ -374,17 +372,16 @@ mod tests {
     fn test_input_pt1() {
         let test_input = include_str!("input.test.txt");
         let mut puzzle: Puzzle = test_input.parse().unwrap();
-        // println!("{}", puzzle.map);
+        // eprintln!("{}", puzzle.map);
         puzzle.apply_moves();
 
-        // println!("Final:\n{}", puzzle.map);
+        // eprintln!("Final:\n{}", puzzle.map);
         assert_eq!(10092, sum_of_gps(&Tile::Box, &puzzle.map));
     }
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1515788, part1(test_input));
+        aoclib::answers::assert_correct::<Day15>(Part::One);
     }
 
     #[test]
@@ -392,7 +389,7 @@ mod tests {
         let test_input = include_str!("input.test.txt");
         let puzzle: Puzzle = test_input.parse().unwrap();
         let p2: Puzzle2 = puzzle.into();
-        // println!("{}", p2.map);
+        // eprintln!("{}", p2.map);
         assert_eq!(20, p2.map.width());
     }
 
@@ -400,15 +397,14 @@ mod tests {
     fn test_input_pt2_steps() {
         let test_input = include_str!("input.test.txt");
         let mut puzzle: Puzzle2 = test_input.parse::<Puzzle>().unwrap().into();
-        // println!("{}", puzzle.map);
+        // eprintln!("{}", puzzle.map);
         puzzle.apply_moves();
-        // println!("Final Map:\n{}", puzzle.map);
+        // eprintln!("Final Map:\n{}", puzzle.map);
         assert_eq!(9021, sum_of_gps(&Tile2::LeftBox, &puzzle.map));
     }
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1516544, part2(test_input));
+        aoclib::answers::assert_correct::<Day15>(Part::Two);
     }
 }
@@ -1,21 +1,18 @@
 use core::str;
-use std::{str::FromStr, time::Instant, usize};
+use std::{str::FromStr, usize};
 
 use aoclib::{
     grid::{FromChar, Grid, GridPosition},
     shortest_path::{Cost, Heuristic, ManhattenDistanceTo, Neighbours, astar},
-    timing,
 };
 use hashbrown::HashSet;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day16>();
 }
 
+aoclib::solution!(Day16, 2024, 16);
+
 fn part1(txt: &str) -> usize {
     let map: Map = txt.parse().unwrap();
 
@@ -242,7 +239,8 @@ enum Direction {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -252,8 +250,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(115500, part1(test_input));
+        aoclib::answers::assert_correct::<Day16>(Part::One);
     }
 
     #[test]
@@ -264,7 +261,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(679, part2(test_input));
+        aoclib::answers::assert_correct::<Day16>(Part::Two);
     }
 }
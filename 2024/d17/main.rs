@@ -1,16 +1,12 @@
 use core::str;
-use std::{i64, time::Instant, usize};
-
-use aoclib::timing;
+use std::{i64, usize};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day17>();
 }
 
+aoclib::solution!(Day17, 2024, 17);
+
 fn part1(txt: &str) -> String {
     let mut lines_itr = txt.lines();
     let (_, a) = lines_itr.next().unwrap().split_once(": ").unwrap();
@@ -59,19 +55,19 @@ fn part2(txt: &str) -> i64 {
         reg.process(0, &program);
 
         if reg.output == "2,4,1,1,7,5,1,5,4,2,5,5,0,3,3,0" {
-            println!("{} => {}", i, reg.output);
+            eprintln!("{} => {}", i, reg.output);
             // break;
         }
         i -= 1;
 
         // if i % 1000000 == 0 {
-        //     println!("{} => {}", i, reg.output);
+        //     eprintln!("{} => {}", i, reg.output);
         // }
     }
 
     // let mut i = 0;
 
-    // println!("enter an incrememnt or decrement");
+    // eprintln!("enter an incrememnt or decrement");
     // let stdin = io::stdin();
     // for line in stdin.lock().lines() {
     //     let line_str = line.unwrap();
@@ -85,7 +81,7 @@ fn part2(txt: &str) -> i64 {
     //             reg.c = 0;
     //             reg.output = String::new();
     //             reg.process(0, &program);
-    //             println!("{} => {}", i, reg.output);
+    //             eprintln!("{} => {}", i, reg.output);
     //         },
     //         "-" => {
     //             let decrement: i64 = line_str[1..].parse().unwrap();
@@ -95,10 +91,10 @@ fn part2(txt: &str) -> i64 {
     //             reg.c = 0;
     //             reg.output = String::new();
     //             reg.process(0, &program);
-    //             println!("{} => {}", i, reg.output);
+    //             eprintln!("{} => {}", i, reg.output);
     //         },
     //         _ignored => {
-    //             println!("ignored");
+    //             eprintln!("ignored");
     //         },
     //     }
     // }
@@ -300,7 +296,8 @@ impl Registers {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_smaller_examples() {
@@ -342,8 +339,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!("3,6,7,0,5,7,3,1,4", part1(test_input));
+        aoclib::answers::assert_correct::<Day17>(Part::One);
     }
 
     // #[test]
@@ -1,22 +1,19 @@
 use core::str;
-use std::{i64, time::Instant, usize};
+use std::{i64, usize};
 
 use aoclib::{
     cartesian::{Plane, Point, Transform, Vector},
     distance::{Distance, ManhattenDistance},
     shortest_path::{Cost, Heuristic, Neighbours, astar},
-    timing,
 };
 use hashbrown::HashSet;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day18>();
 }
 
+aoclib::solution!(Day18, 2024, 18);
+
 fn part1(txt: &str) -> i64 {
     min_steps(txt, 1024, Point::new(70, 70))
 }
@@ -145,7 +142,7 @@ impl Neighbours<Position> for Part1State {
             n.push(Position(down));
         }
 
-        // println!("{:?} -> {:?}", state.0, n);
+        // eprintln!("{:?} -> {:?}", state.0, n);
 
         n
     }
@@ -171,7 +168,8 @@ impl Heuristic<Position, i64> for Position {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -181,8 +179,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(290, part1(test_input));
+        aoclib::answers::assert_correct::<Day18>(Part::One);
     }
 
     #[test]
@@ -193,7 +190,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!("64,-54", part2(test_input));
+        aoclib::answers::assert_correct::<Day18>(Part::Two);
     }
 }
@@ -1,18 +1,16 @@
 use core::str;
-use std::{i64, time::Instant, usize};
+use std::{i64, usize};
 
-use aoclib::{input, timing};
+use aoclib::input;
 use hashbrown::HashMap;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day19>();
 }
 
+aoclib::solution!(Day19, 2024, 19);
+
 fn part1(txt: &str) -> i64 {
     let (patterns, to_check) = parse(txt);
     let mut c = 0;
@@ -101,7 +99,8 @@ struct TowelPattern<'a>(&'a str);
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_find() {
@@ -142,8 +141,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(344, part1(test_input));
+        aoclib::answers::assert_correct::<Day19>(Part::One);
     }
 
     #[test]
@@ -166,7 +164,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(996172272010026, part2(test_input));
+        aoclib::answers::assert_correct::<Day19>(Part::Two);
     }
 }
@@ -1,18 +1,13 @@
-use std::{str::FromStr, time::Instant};
+use std::str::FromStr;
 
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day2>();
 }
 
+aoclib::solution!(Day2, 2024, 2);
+
 fn part1(txt: &str) -> usize {
     solve::<Part1>(txt)
 }
@@ -113,7 +108,8 @@ fn any_safe(r: &Report) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn sample_input_pt1_lines() {
@@ -155,8 +151,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(246, part1(test_input));
+        aoclib::answers::assert_correct::<Day2>(Part::One);
     }
 
     #[test]
@@ -167,7 +162,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(318, part2(test_input));
+        aoclib::answers::assert_correct::<Day2>(Part::Two);
     }
 }
@@ -1,20 +1,15 @@
 use core::str;
-use std::{i64, time::Instant, usize};
+use std::{i64, usize};
 
-use aoclib::{
-    grid::{FromChar, Grid, GridPosition},
-    timing,
-};
+use aoclib::grid::{FromChar, Grid, GridPosition};
 use hashbrown::HashMap;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day20>();
 }
 
+aoclib::solution!(Day20, 2024, 20);
+
 fn part1(txt: &str) -> usize {
     find_all_shortcuts(txt, 100)
 }
@@ -25,8 +20,8 @@ fn part2(txt: &str) -> usize {
 }
 
 fn list_savings(txt: &str, min: usize, picoseconds: usize) -> HashMap<usize, usize> {
-    let g: Grid<Tile> = txt.parse().unwrap();
-    let normal_path = path(&g);
+    let (g, at) = Grid::<Tile>::parse_marked(txt, &['S', 'E']).unwrap();
+    let normal_path = path(&g, at['S'], at['E']);
 
     let mut savings: HashMap<usize, usize> = HashMap::new();
     for i in 0..normal_path.len() {
@@ -48,9 +43,9 @@ fn list_savings(txt: &str, min: usize, picoseconds: usize) -> HashMap<usize, usi
 }
 
 fn find_all_shortcuts(txt: &str, gte: usize) -> usize {
-    let g: Grid<Tile> = txt.parse().unwrap();
-    let normal_path = path(&g);
-    let e = end(&g);
+    let (g, at) = Grid::<Tile>::parse_marked(txt, &['S', 'E']).unwrap();
+    let e = at['E'];
+    let normal_path = path(&g, at['S'], e);
     let mut position_index: HashMap<GridPosition, usize> = normal_path
         .iter()
         .cloned()
@@ -187,10 +182,10 @@ fn build_shortcuts(
     shortcuts
 }
 
-fn path(g: &Grid<Tile>) -> Vec<GridPosition> {
+fn path(g: &Grid<Tile>, start: GridPosition, end: GridPosition) -> Vec<GridPosition> {
     let mut path: Vec<GridPosition> = Vec::new();
 
-    let mut current = start(&g);
+    let mut current = start;
     path.push(current);
     let mut prev = current;
     while let Some(p) = next(&g, &prev, &current) {
@@ -198,7 +193,7 @@ fn path(g: &Grid<Tile>) -> Vec<GridPosition> {
         current = p;
         path.push(p.clone());
     }
-    path.push(end(&g));
+    path.push(end);
     path
 }
 
@@ -230,22 +225,6 @@ fn next(g: &Grid<Tile>, prev: &GridPosition, current: &GridPosition) -> Option<G
     None
 }
 
-fn start(g: &Grid<Tile>) -> GridPosition {
-    let (p, _) = g
-        .position_itr()
-        .find(|(_p, t)| t == &&Tile::Start)
-        .expect("Start");
-    p
-}
-
-fn end(g: &Grid<Tile>) -> GridPosition {
-    let (p, _) = g
-        .position_itr()
-        .find(|(_p, t)| t == &&Tile::End)
-        .expect("End");
-    p
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Tile {
     Wall,
@@ -270,9 +249,10 @@ impl FromChar for Tile {
 
 #[cfg(test)]
 mod tests {
+    use aoclib::solution::Part;
     use itertools::Itertools;
 
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_input_pt1() {
@@ -282,8 +262,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1502, part1(test_input));
+        aoclib::answers::assert_correct::<Day20>(Part::One);
     }
 
     #[test]
@@ -300,7 +279,7 @@ mod tests {
     #[allow(dead_code)]
     fn print_savings(savings: HashMap<usize, usize>) {
         for (saving, number) in savings.iter().sorted_by_key(|(saving, _number)| *saving) {
-            println!(
+            eprintln!(
                 "There are {} cheats that save {} picoseconds",
                 number, saving
             );
@@ -309,14 +288,9 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
         // 1045 too low
         // 22951 too low
         // 2789483 too high
-        let pt2 = part2(test_input);
-        assert!(pt2 > 1045, "{}", pt2);
-        assert!(pt2 > 22951, "{}", pt2);
-        assert!(pt2 < 2789483, "{}", pt2);
-        assert_eq!(1028136, pt2);
+        aoclib::answers::assert_correct::<Day20>(Part::Two);
     }
 }
@@ -1,21 +1,16 @@
 use core::fmt;
-use std::{array, i64, str::FromStr, time::Instant, usize};
+use std::{array, i64, str::FromStr, usize};
 
-use aoclib::{
-    grid::{FromChar, Grid, GridPosition},
-    timing,
-};
+use aoclib::grid::{FromChar, Grid, GridPosition};
 
 use hashbrown::HashMap;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day21>();
 }
 
+aoclib::solution!(Day21, 2024, 21);
+
 fn part1(txt: &str) -> usize {
     let input: Input = txt.parse().unwrap();
     let mut chain: RobotChain<2> = RobotChain::default();
@@ -64,9 +59,8 @@ impl FromStr for Input {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let g: Grid<NumericKeypadTile> = s.parse()?;
         let items = g
-            .rows
-            .into_iter()
-            .map(|row| NumericSequence(row.try_into().expect("exactly 4 items")))
+            .rows()
+            .map(|row| NumericSequence(row.to_vec().try_into().expect("exactly 4 items")))
             .collect();
         Ok(Input(items))
     }
@@ -182,30 +176,28 @@ where
 
 impl Default for KeypadRobot<NumericKeypadTile> {
     fn default() -> Self {
-        let grid = Grid {
-            rows: vec![
-                vec![
-                    NumericKeypadTile::Number(7),
-                    NumericKeypadTile::Number(8),
-                    NumericKeypadTile::Number(9),
-                ],
-                vec![
-                    NumericKeypadTile::Number(4),
-                    NumericKeypadTile::Number(5),
-                    NumericKeypadTile::Number(6),
-                ],
-                vec![
-                    NumericKeypadTile::Number(1),
-                    NumericKeypadTile::Number(2),
-                    NumericKeypadTile::Number(3),
-                ],
-                vec![
-                    NumericKeypadTile::Blank,
-                    NumericKeypadTile::Number(0),
-                    NumericKeypadTile::A,
-                ],
+        let grid = Grid::new(vec![
+            vec![
+                NumericKeypadTile::Number(7),
+                NumericKeypadTile::Number(8),
+                NumericKeypadTile::Number(9),
             ],
-        };
+            vec![
+                NumericKeypadTile::Number(4),
+                NumericKeypadTile::Number(5),
+                NumericKeypadTile::Number(6),
+            ],
+            vec![
+                NumericKeypadTile::Number(1),
+                NumericKeypadTile::Number(2),
+                NumericKeypadTile::Number(3),
+            ],
+            vec![
+                NumericKeypadTile::Blank,
+                NumericKeypadTile::Number(0),
+                NumericKeypadTile::A,
+            ],
+        ]);
         let position = GridPosition::new(2, 3);
         assert!(grid.at(&position) == &NumericKeypadTile::A);
         Self {
@@ -218,20 +210,18 @@ impl Default for KeypadRobot<NumericKeypadTile> {
 
 impl Default for KeypadRobot<DirectionalKeypadTile> {
     fn default() -> Self {
-        let grid = Grid {
-            rows: vec![
-                vec![
-                    DirectionalKeypadTile::Blank,
-                    DirectionalKeypadTile::Up,
-                    DirectionalKeypadTile::A,
-                ],
-                vec![
-                    DirectionalKeypadTile::Left,
-                    DirectionalKeypadTile::Down,
-                    DirectionalKeypadTile::Right,
-                ],
+        let grid = Grid::new(vec![
+            vec![
+                DirectionalKeypadTile::Blank,
+                DirectionalKeypadTile::Up,
+                DirectionalKeypadTile::A,
             ],
-        };
+            vec![
+                DirectionalKeypadTile::Left,
+                DirectionalKeypadTile::Down,
+                DirectionalKeypadTile::Right,
+            ],
+        ]);
         let position = GridPosition::new(2, 0);
         assert!(grid.at(&position) == &DirectionalKeypadTile::A);
         Self {
@@ -290,19 +280,19 @@ impl KeypadRobot<DirectionalKeypadTile> {
 #[allow(dead_code)]
 fn debug_moves(moves: &Vec<DirectionalKeypadTile>) {
     for m in moves {
-        print!("{}", m);
+        eprint!("{}", m);
     }
-    println!("");
+    eprintln!("");
 }
 
 #[allow(dead_code)]
 fn debug_reverse_moves(txt: &str) {
     let mut chain: RobotChain<2> = RobotChain::default();
     let g: Grid<DirectionalKeypadTile> = txt.parse().unwrap();
-    println!("");
+    eprintln!("");
     for (_, tile) in g.position_itr() {
         if let Some(out) = chain.move_arm(tile.clone()) {
-            println!("{:?}", out);
+            eprintln!("{:?}", out);
         }
     }
 }
@@ -727,17 +717,17 @@ impl<const R: usize> RobotChain<R> {
     fn move_arm(&mut self, dir: DirectionalKeypadTile) -> Option<NumericKeypadTile> {
         match dir {
             DirectionalKeypadTile::A => {
-                // println!("directional[0] queue: {:?}", self.directional[0].move_queue);
+                // eprintln!("directional[0] queue: {:?}", self.directional[0].move_queue);
                 let m = self.directional[0].flush_moves().clone();
                 match m {
                     DirectionalKeypadTile::A => {
-                        // println!("directional[1] queue: {:?}", self.directional[0].move_queue);
+                        // eprintln!("directional[1] queue: {:?}", self.directional[0].move_queue);
                         let m1 = self.directional[1].flush_moves().clone();
                         match m1 {
                             DirectionalKeypadTile::A => {
                                 // apply final layer
                                 let num = self.numeric.grid.at(&self.numeric.position).clone();
-                                // println!("numberic queue: {:?}", self.numeric.move_queue);
+                                // eprintln!("numberic queue: {:?}", self.numeric.move_queue);
                                 self.numeric.move_queue.clear();
                                 return Some(num);
                             }
@@ -770,7 +760,8 @@ impl<const R: usize> RobotChain<R> {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_moves() {
@@ -819,28 +810,13 @@ mod tests {
     }
 
     #[test]
-    fn test_input_pt1() {
-        let mut chain: RobotChain<2> = RobotChain::default();
-        let test_input = include_str!("input.test.txt");
-        let example: Input = test_input.parse().unwrap();
-        let results: Vec<_> = example
-            .0
-            .into_iter()
-            .map(|n| n.complexity(&mut chain))
-            .collect();
-        // 68 * 29, 60 * 980, 68 * 179, 64 * 456, and 64 * 379
-        assert_eq!(
-            results,
-            vec![68 * 29, 60 * 980, 68 * 179, 64 * 456, 64 * 379]
-        );
-        assert_eq!(126384, part1(test_input));
+    fn examples() {
+        aoclib::examples::assert_examples::<Day21>();
     }
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        let pt1 = part1(test_input);
-        assert_eq!(184718, pt1);
+        aoclib::answers::assert_correct::<Day21>(Part::One);
     }
 
     // #[test]
@@ -854,7 +830,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(228800606998554, part2(test_input));
+        aoclib::answers::assert_correct::<Day21>(Part::Two);
     }
 }
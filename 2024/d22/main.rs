@@ -3,21 +3,17 @@ use std::{
     i64,
     iter::{self, Sum},
     ops::Add,
-    time::Instant,
 };
 
-use aoclib::timing;
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day22>();
 }
 
+aoclib::solution!(Day22, 2024, 22);
+
 fn part1(txt: &str) -> i64 {
     let SecretNumber(total) = txt
         .lines()
@@ -131,7 +127,8 @@ impl PriceSequence {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_mix() {
@@ -191,8 +188,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(20411980517, part1(test_input));
+        aoclib::answers::assert_correct::<Day22>(Part::One);
     }
 
     #[test]
@@ -226,7 +222,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(2362, part2(test_input));
+        aoclib::answers::assert_correct::<Day22>(Part::Two);
     }
 }
@@ -3,22 +3,18 @@ use std::{
     fmt::{Display, Write},
     hash::Hash,
     str::FromStr,
-    time::Instant,
     usize,
 };
 
-use aoclib::timing;
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day23>();
 }
 
+aoclib::solution!(Day23, 2024, 23);
+
 fn part1(txt: &str) -> usize {
     let mut all_connections: HashMap<Computer, HashSet<Computer>> = HashMap::new();
     let mut ch_computers: HashSet<Computer> = HashSet::new();
@@ -192,7 +188,8 @@ fn bron_kerbosch<T>(
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -202,8 +199,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1184, part1(test_input));
+        aoclib::answers::assert_correct::<Day23>(Part::One);
     }
 
     #[test]
@@ -214,7 +210,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!("hf,hz,lb,lm,ls,my,ps,qu,ra,uc,vi,xz,yv", part2(test_input));
+        aoclib::answers::assert_correct::<Day23>(Part::Two);
     }
 }
@@ -4,21 +4,18 @@ use std::{
     fmt::{Display, Write},
     iter,
     str::FromStr,
-    time::Instant,
 };
 
-use aoclib::{input, timing};
+use aoclib::input;
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day24>();
 }
 
+aoclib::solution!(Day24, 2024, 24);
+
 fn part1(txt: &str) -> u64 {
     let chunks: Vec<_> = input::empty_line_chunks(txt).collect();
     let wires: Vec<Wire> = chunks[0].lines().map(|l| l.parse().unwrap()).collect();
@@ -135,7 +132,7 @@ fn part2(txt: &str) -> String {
                     || c.from_right.starts_with("x")
                     || c.from_right.starts_with("y")
                 {
-                    println!(
+                    eprintln!(
                         "[{}] A gate with Zxx as its output cannot directly use Xn or Yn as inputs exept the first bit Z00",
                         &c.to
                     );
@@ -145,7 +142,7 @@ fn part2(txt: &str) -> String {
 
             if c.to != "z45" {
                 if &c.gate != &Gate::XOR {
-                    println!("[{}] Output to a zxx should always be an XOR", c.to);
+                    eprintln!("[{}] Output to a zxx should always be an XOR", c.to);
                     rule_breaks.insert(c.clone());
                 }
             }
@@ -155,7 +152,7 @@ fn part2(txt: &str) -> String {
             Gate::AND => {
                 if let Some(input_to) = connections_by_input.get(&c.to) {
                     if input_to.contains_key(&Gate::AND) || input_to.contains_key(&Gate::XOR) {
-                        println!("[{}] AND gate can only be input to an OR gate", c.to);
+                        eprintln!("[{}] AND gate can only be input to an OR gate", c.to);
                         rule_breaks.insert(c.clone());
                     }
                 }
@@ -165,7 +162,7 @@ fn part2(txt: &str) -> String {
                         && input_left.from_right != "x00"
                         && input_left.gate == Gate::AND
                     {
-                        println!(
+                        eprintln!(
                             "[{}] AND gate cannot take other AND gate as input",
                             input_left.to
                         );
@@ -177,7 +174,7 @@ fn part2(txt: &str) -> String {
                         && input_right.from_right != "x00"
                         && input_right.gate == Gate::AND
                     {
-                        println!(
+                        eprintln!(
                             "[{}] AND gate cannot take other AND gate as input",
                             input_right.to
                         );
@@ -188,7 +185,7 @@ fn part2(txt: &str) -> String {
             Gate::XOR => {
                 if !c.from_left.starts_with("x") && !c.from_left.starts_with("y") {
                     if !c.to.starts_with("z") {
-                        println!(
+                        eprintln!(
                             "[{}] XOR gate must output to z if non input {} XOR {} -> {}",
                             c.to, c.from_left, c.from_right, c.to
                         );
@@ -198,19 +195,19 @@ fn part2(txt: &str) -> String {
 
                 if let Some(input_to) = connections_by_input.get(&c.to) {
                     if input_to.contains_key(&Gate::OR) {
-                        println!("[{}] XOR gate can only be input to an AND/XOR gate", c.to);
+                        eprintln!("[{}] XOR gate can only be input to an AND/XOR gate", c.to);
                         rule_breaks.insert(c.clone());
                     }
                 }
                 if let Some(input_left) = initial_connection_by_output.get(&c.from_left) {
                     if c.to != "z01" && input_left.gate == Gate::AND {
-                        println!("[{}] XOR gate cannot take AND gate as input", input_left.to);
+                        eprintln!("[{}] XOR gate cannot take AND gate as input", input_left.to);
                         rule_breaks.insert(input_left.clone());
                     }
                 }
                 if let Some(input_right) = initial_connection_by_output.get(&c.from_right) {
                     if c.to != "z01" && input_right.gate == Gate::AND {
-                        println!(
+                        eprintln!(
                             "[{}] XOR gate cannot take AND gate as input",
                             input_right.to
                         );
@@ -221,13 +218,13 @@ fn part2(txt: &str) -> String {
             Gate::OR => {
                 if let Some(input_to) = connections_by_input.get(&c.to) {
                     if input_to.contains_key(&Gate::OR) {
-                        println!("[{}] OR gate can only be input of AND/XOR gate", c.to);
+                        eprintln!("[{}] OR gate can only be input of AND/XOR gate", c.to);
                         rule_breaks.insert(c.clone());
                     }
                 }
                 if let Some(input_left) = initial_connection_by_output.get(&c.from_left) {
                     if input_left.gate != Gate::AND {
-                        println!(
+                        eprintln!(
                             "[{}] OR gate can only take AND gate as input",
                             input_left.to
                         );
@@ -236,7 +233,7 @@ fn part2(txt: &str) -> String {
                 }
                 if let Some(input_right) = initial_connection_by_output.get(&c.from_right) {
                     if input_right.gate != Gate::AND {
-                        println!(
+                        eprintln!(
                             "[{}] OR gate can only take AND gate as input",
                             input_right.to
                         );
@@ -262,7 +259,7 @@ fn wire_value(
     seen: &mut HashSet<String>,
 ) -> Option<Wire> {
     if let Some(wire) = known_wires.get(&name) {
-        // println!("wire value: {} = {}", name, wire.value);
+        // eprintln!("wire value: {} = {}", name, wire.value);
         return Some(wire.clone());
     }
     if !seen.insert(name.clone()) {
@@ -300,7 +297,7 @@ fn wire_value(
     };
     known_wires.insert(name.clone(), new_wire.clone());
 
-    // println!("wire value: {} = {}", name, new_wire.value);
+    // eprintln!("wire value: {} = {}", name, new_wire.value);
     return Some(new_wire);
 }
 
@@ -386,7 +383,8 @@ impl Display for Wire {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1_small() {
@@ -402,13 +400,11 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(47666458872582, part1(test_input));
+        aoclib::answers::assert_correct::<Day24>(Part::One);
     }
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!("dnt,gdf,gwc,jst,mcm,z05,z15,z30", part2(test_input));
+        aoclib::answers::assert_correct::<Day24>(Part::Two);
     }
 }
@@ -1,13 +1,34 @@
 use core::str;
-use std::{i64, str::FromStr, time::Instant, usize};
+use std::{i64, str::FromStr, usize};
 
-use aoclib::{input, timing};
+use aoclib::{
+    Answer, input,
+    solution::{NoAnswer, Solution},
+};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day25>();
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+
+    type Input<'a> = &'a str;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> impl Into<Answer> {
+        NoAnswer
+    }
 }
 
 fn part1(txt: &str) -> i64 {
@@ -98,7 +119,8 @@ impl FromStr for Schematic {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_parse() {
@@ -134,7 +156,6 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(3021, part1(test_input));
+        aoclib::answers::assert_correct::<Day25>(Part::One);
     }
 }
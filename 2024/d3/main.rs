@@ -1,18 +1,13 @@
-use std::{sync::LazyLock, time::Instant};
+use std::sync::LazyLock;
 
 use regex::Regex;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day3>();
 }
 
+aoclib::solution!(Day3, 2024, 3);
+
 static RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap());
 
 fn part1(txt: &str) -> i64 {
@@ -34,7 +29,8 @@ fn part2(txt: &str) -> i64 {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -44,8 +40,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(189600467, part1(test_input));
+        aoclib::answers::assert_correct::<Day3>(Part::One);
     }
 
     #[test]
@@ -56,7 +51,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(107069718, part2(test_input));
+        aoclib::answers::assert_correct::<Day3>(Part::Two);
     }
 }
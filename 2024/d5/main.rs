@@ -3,23 +3,17 @@ use std::{
     cmp::Ordering,
     collections::{HashMap, HashSet},
     str::FromStr,
-    time::Instant,
 };
 
 use aoclib::input;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day5>();
 }
 
+aoclib::solution!(Day5, 2024, 5);
+
 fn part1(txt: &str) -> i64 {
     let parts = input::empty_line_chunks(txt).collect_vec();
     let ord: PageOrdering = parts[0].parse().unwrap();
@@ -128,7 +122,8 @@ impl FromStr for PageOrdering {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -138,8 +133,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(5108, part1(test_input));
+        aoclib::answers::assert_correct::<Day5>(Part::One);
     }
 
     #[test]
@@ -150,7 +144,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(7380, part2(test_input));
+        aoclib::answers::assert_correct::<Day5>(Part::Two);
     }
 }
@@ -1,50 +1,22 @@
 use core::str;
-use std::time::Instant;
 
 use hashbrown::HashSet;
 
-use aoclib::grid::{FromChar, Grid, GridPosition};
+use aoclib::grid::{Direction, FromChar, Grid, GridPosition};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{:.2}s", now.elapsed().as_secs_f64());
+    aoclib::solution::main::<Day6>();
 }
 
+aoclib::solution!(Day6, 2024, 6);
+
 fn part1(txt: &str) -> i64 {
-    let g: Grid<Tile> = txt.parse().unwrap();
-    let guard_position = {
-        let mut it = None;
-        for row in 0..g.height() {
-            for col in 0..g.width() {
-                let p = GridPosition::new(col, row);
-                if g.at(&p) == &Tile::Guard {
-                    it = Some(p);
-                }
-            }
-        }
-        it.unwrap()
-    };
+    let (g, guard_position) = parse(txt);
     path(&g, &guard_position).len() as i64
 }
 
 fn part2(txt: &str) -> i64 {
-    let mut g: Grid<Tile> = txt.parse().unwrap();
-
-    let guard_position = {
-        let mut it = None;
-        for row in 0..g.height() {
-            for col in 0..g.width() {
-                let p = GridPosition::new(col, row);
-                if g.at(&p) == &Tile::Guard {
-                    it = Some(p);
-                }
-            }
-        }
-        it.unwrap()
-    };
+    let (mut g, guard_position) = parse(txt);
     let path = path(&g, &guard_position);
 
     let direction = Direction::Up;
@@ -56,29 +28,32 @@ fn part2(txt: &str) -> i64 {
         if g.at(&test_pos) == &Tile::Obstruction {
             continue;
         }
-        g.rows[test_pos.row][test_pos.col] = Tile::Obstruction;
+        g[test_pos] = Tile::Obstruction;
         if loops(direction, &visited, &guard_position, &g) {
             count += 1;
         }
-        g.rows[test_pos.row][test_pos.col] = Tile::Space;
+        g[test_pos] = Tile::Space;
     }
     count
 }
 
+/// The lab with the guard's square as floor, and where they start
+fn parse(txt: &str) -> (Grid<Tile>, GridPosition) {
+    let (g, markers) = Grid::parse_marked_with(txt, &['^'], |_| Ok(Tile::Space)).unwrap();
+    (g, markers['^'])
+}
+
 fn path(g: &Grid<Tile>, guard_position: &GridPosition) -> HashSet<GridPosition> {
     let mut guard_position = guard_position.clone();
     let mut visited: HashSet<GridPosition> = HashSet::from_iter(vec![guard_position]);
     let mut direction = Direction::Up;
 
-    while let Some(next_pos) = direction.make_move(&guard_position) {
-        if next_pos.row == g.height() || next_pos.col == g.width() {
-            break;
-        }
+    while let Some(next_pos) = g.step(&guard_position, direction) {
         match g.at(&next_pos) {
             Tile::Obstruction => {
                 direction = direction.turn_right();
             }
-            Tile::Guard | Tile::Space => {
+            Tile::Space => {
                 guard_position = next_pos;
                 visited.insert(guard_position);
             }
@@ -97,15 +72,12 @@ fn loops(
     let mut direction = direction;
     let mut visited = visited.clone();
 
-    while let Some(next_pos) = direction.make_move(&guard_position) {
-        if next_pos.row == g.height() || next_pos.col == g.width() {
-            break;
-        }
+    while let Some(next_pos) = g.step(&guard_position, direction) {
         match g.at(&next_pos) {
             Tile::Obstruction => {
                 direction = direction.turn_right();
             }
-            Tile::Guard | Tile::Space => {
+            Tile::Space => {
                 guard_position = next_pos;
                 if !visited.insert((guard_position, direction)) {
                     return true;
@@ -119,50 +91,9 @@ fn loops(
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 enum Tile {
     Obstruction,
-    Guard,
     Space,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn turn_right(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-            Direction::Right => Direction::Down,
-        }
-    }
-
-    fn make_move(&self, p: &GridPosition) -> Option<GridPosition> {
-        match self {
-            Direction::Up => {
-                if p.row == 0 {
-                    None
-                } else {
-                    Some(p.up())
-                }
-            }
-            Direction::Down => Some(p.down()),
-            Direction::Left => {
-                if p.col == 0 {
-                    None
-                } else {
-                    Some(p.left())
-                }
-            }
-            Direction::Right => Some(p.right()),
-        }
-    }
-}
-
 impl FromChar for Tile {
     type Err = String;
 
@@ -170,7 +101,6 @@ impl FromChar for Tile {
         match c {
             '.' => Ok(Tile::Space),
             '#' => Ok(Tile::Obstruction),
-            '^' => Ok(Tile::Guard),
             other => Err(format!("bad position {}", other)),
         }
     }
@@ -178,7 +108,8 @@ impl FromChar for Tile {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -188,8 +119,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(5318, part1(test_input));
+        aoclib::answers::assert_correct::<Day6>(Part::One);
     }
 
     #[test]
@@ -200,7 +130,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1831, part2(test_input));
+        aoclib::answers::assert_correct::<Day6>(Part::Two);
     }
 }
@@ -1,17 +1,12 @@
 use core::str;
-use std::{i64, str::FromStr, time::Instant, usize};
+use std::{i64, str::FromStr, usize};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!(
-        "{:.2}ms",
-        (now.elapsed().subsec_nanos() as f32) / 1_000_000 as f32
-    );
+    aoclib::solution::main::<Day7>();
 }
 
+aoclib::solution!(Day7, 2024, 7);
+
 fn part1(txt: &str) -> i64 {
     solve(txt, [Operation::Plus, Operation::Mul])
 }
@@ -97,7 +92,8 @@ impl Calibration {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -107,8 +103,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1038838357795, part1(test_input));
+        aoclib::answers::assert_correct::<Day7>(Part::One);
     }
 
     #[test]
@@ -119,7 +114,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(254136560217241, part2(test_input));
+        aoclib::answers::assert_correct::<Day7>(Part::Two);
     }
 }
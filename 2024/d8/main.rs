@@ -1,21 +1,19 @@
 use core::str;
-use std::{i64, time::Instant, usize};
+use std::{fmt::Display, i64, usize};
 
 use aoclib::{
     grid::{FromChar, Grid, GridPosition},
-    timing,
+    render::render,
 };
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day8>();
 }
 
+aoclib::solution!(Day8, 2024, 8);
+
 fn part1(txt: &str) -> usize {
     let g: Grid<Tile> = txt.parse().unwrap();
 
@@ -29,8 +27,8 @@ fn part1(txt: &str) -> usize {
         }
     }
 
-    // print(&g);
-    // println!("");
+    // eprint!("{g}");
+    // eprintln!("");
     // print_with_antinodes(&g, &antinodes);
 
     antinodes.len()
@@ -49,8 +47,8 @@ fn part2(txt: &str) -> usize {
         }
     }
 
-    // print(&g);
-    // println!("");
+    // eprint!("{g}");
+    // eprintln!("");
     // print_with_antinodes(&g, &antinodes);
     antinodes.union(&antenna_position_set).count()
 }
@@ -86,38 +84,9 @@ fn antenna_positions(
     (antenna_positions, antenna_position_set)
 }
 
-#[allow(dead_code)]
-fn print(g: &Grid<Tile>) {
-    for row in 0..g.height() {
-        for col in 0..g.width() {
-            let p = GridPosition::new(col, row);
-            let t = g.at(&p);
-            match t {
-                Tile::Antenna(c) => print!("{c}"),
-                Tile::Space => print!("."),
-            }
-        }
-        println!("");
-    }
-}
-
 #[allow(dead_code)]
 fn print_with_antinodes(g: &Grid<Tile>, antinodes: &HashSet<GridPosition>) {
-    for row in 0..g.height() {
-        for col in 0..g.width() {
-            let p = GridPosition::new(col, row);
-            let t = g.at(&p);
-            if antinodes.contains(&p) {
-                print!("#")
-            } else {
-                match t {
-                    Tile::Antenna(c) => print!("{c}"),
-                    Tile::Space => print!("."),
-                }
-            }
-        }
-        println!("");
-    }
+    eprint!("{}", render(g).highlight(antinodes.iter().copied(), '#'));
 }
 
 fn calc_antinodes(a: &GridPosition, b: &GridPosition, g: &Grid<Tile>) -> HashSet<GridPosition> {
@@ -184,6 +153,15 @@ enum Tile {
     Space,
 }
 
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Antenna(c) => write!(f, "{c}"),
+            Tile::Space => f.write_str("."),
+        }
+    }
+}
+
 impl FromChar for Tile {
     type Err = String;
 
@@ -197,20 +175,19 @@ impl FromChar for Tile {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_antinode_positions() {
         let a = GridPosition::new(1, 1);
         let b = GridPosition::new(2, 2);
-        let g = Grid {
-            rows: vec![
-                vec![Tile::Space, Tile::Space, Tile::Space, Tile::Space],
-                vec![Tile::Space, Tile::Space, Tile::Space, Tile::Space],
-                vec![Tile::Space, Tile::Space, Tile::Space, Tile::Space],
-                vec![Tile::Space, Tile::Space, Tile::Space, Tile::Space],
-            ],
-        };
+        let g = Grid::new(vec![
+            vec![Tile::Space, Tile::Space, Tile::Space, Tile::Space],
+            vec![Tile::Space, Tile::Space, Tile::Space, Tile::Space],
+            vec![Tile::Space, Tile::Space, Tile::Space, Tile::Space],
+            vec![Tile::Space, Tile::Space, Tile::Space, Tile::Space],
+        ]);
         let antinodes = calc_antinodes(&a, &b, &g);
         assert_eq!(true, antinodes.contains(&GridPosition::new(0, 0)));
         assert_eq!(true, antinodes.contains(&GridPosition::new(3, 3)));
@@ -228,8 +205,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(247, part1(test_input));
+        aoclib::answers::assert_correct::<Day8>(Part::One);
     }
 
     #[test]
@@ -240,7 +216,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(861, part2(test_input));
+        aoclib::answers::assert_correct::<Day8>(Part::Two);
     }
 }
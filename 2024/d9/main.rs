@@ -1,17 +1,14 @@
 use core::str;
-use std::{time::Instant, usize};
+use std::usize;
 
-use aoclib::timing;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day9>();
 }
 
+aoclib::solution!(Day9, 2024, 9);
+
 fn part1(txt: &str) -> usize {
     let mut disk: Vec<Block> = Vec::new();
     for (i, c) in txt.chars().enumerate() {
@@ -69,14 +66,14 @@ fn print_disk(d: &Vec<Block>) {
     for b in d {
         match b {
             Block::File(id) => {
-                print!("{id}");
+                eprint!("{id}");
             }
             Block::Space => {
-                print!(".");
+                eprint!(".");
             }
         }
     }
-    println!("");
+    eprintln!("");
 }
 
 fn checksum(disk: &Vec<Block>) -> usize {
@@ -131,7 +128,7 @@ fn part2(txt: &str) -> usize {
         }
         back_position -= 1;
         let b = (&blocks[back_position]).clone();
-        // println!("back_position: {}, {:?}", back_position, b);
+        // eprintln!("back_position: {}, {:?}", back_position, b);
         match b {
             ContiguousBlock::File(File { position, size, id }) => {
                 if let Some(found) = find_free_size(size, &mut blocks[0..back_position]) {
@@ -148,7 +145,7 @@ fn part2(txt: &str) -> usize {
                         disk[j] = Block::Space;
                     }
                     // print_disk(&disk);
-                    // println!("blocks: {:?}", blocks[free_pos]);
+                    // eprintln!("blocks: {:?}", blocks[free_pos]);
                 }
             }
             ContiguousBlock::Space(_) => {
@@ -205,29 +202,21 @@ struct Space {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
-    fn test_input_pt1() {
-        let test_input = include_str!("input.test.txt");
-        assert_eq!(1928, part1(test_input));
+    fn examples() {
+        aoclib::examples::assert_examples::<Day9>();
     }
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(6310675819476, part1(test_input));
-    }
-
-    #[test]
-    fn test_input_pt2() {
-        let test_input = include_str!("input.test.txt");
-        assert_eq!(2858, part2(test_input));
+        aoclib::answers::assert_correct::<Day9>(Part::One);
     }
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(6335972980679, part2(test_input));
+        aoclib::answers::assert_correct::<Day9>(Part::Two);
     }
 }
@@ -0,0 +1,108 @@
+//! Every 2024 day as a module so the `aoc` runner can solve them in-process.
+//! Each day's `main` is only used by its own binary.
+#![allow(dead_code)]
+
+use aoclib::solution::Runnable;
+
+#[path = "d1/main.rs"]
+pub mod d1;
+
+#[path = "d2/main.rs"]
+pub mod d2;
+
+#[path = "d3/main.rs"]
+pub mod d3;
+
+#[path = "d4/main.rs"]
+pub mod d4;
+
+#[path = "d5/main.rs"]
+pub mod d5;
+
+#[path = "d6/main.rs"]
+pub mod d6;
+
+#[path = "d7/main.rs"]
+pub mod d7;
+
+#[path = "d8/main.rs"]
+pub mod d8;
+
+#[path = "d9/main.rs"]
+pub mod d9;
+
+#[path = "d10/main.rs"]
+pub mod d10;
+
+#[path = "d11/main.rs"]
+pub mod d11;
+
+#[path = "d12/main.rs"]
+pub mod d12;
+
+#[path = "d13/main.rs"]
+pub mod d13;
+
+#[path = "d14/main.rs"]
+pub mod d14;
+
+#[path = "d15/main.rs"]
+pub mod d15;
+
+#[path = "d16/main.rs"]
+pub mod d16;
+
+#[path = "d17/main.rs"]
+pub mod d17;
+
+#[path = "d18/main.rs"]
+pub mod d18;
+
+#[path = "d19/main.rs"]
+pub mod d19;
+
+#[path = "d20/main.rs"]
+pub mod d20;
+
+#[path = "d21/main.rs"]
+pub mod d21;
+
+#[path = "d22/main.rs"]
+pub mod d22;
+
+#[path = "d23/main.rs"]
+pub mod d23;
+
+#[path = "d24/main.rs"]
+pub mod d24;
+
+#[path = "d25/main.rs"]
+pub mod d25;
+
+pub static SOLUTIONS: &[&dyn Runnable] = &[
+    &d1::Day1,
+    &d2::Day2,
+    &d3::Day3,
+    &d4::Day4,
+    &d5::Day5,
+    &d6::Day6,
+    &d7::Day7,
+    &d8::Day8,
+    &d9::Day9,
+    &d10::Day10,
+    &d11::Day11,
+    &d12::Day12,
+    &d13::Day13,
+    &d14::Day14,
+    &d15::Day15,
+    &d16::Day16,
+    &d17::Day17,
+    &d18::Day18,
+    &d19::Day19,
+    &d20::Day20,
+    &d21::Day21,
+    &d22::Day22,
+    &d23::Day23,
+    &d24::Day24,
+    &d25::Day25,
+];
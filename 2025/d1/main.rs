@@ -1,16 +1,12 @@
 use core::str;
-use std::{str::FromStr, time::Instant};
-
-use aoclib::timing;
+use std::str::FromStr;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day1>();
 }
 
+aoclib::solution!(Day1, 2025, 1);
+
 fn part1(txt: &str) -> i64 {
     let counter: Counter = txt
         .lines()
@@ -106,7 +102,7 @@ impl Counter2 {
             (_, _) => 0,
         };
 
-        // println!("next: {}, passes: {}, sign change {}", next, passes, sign_change);
+        // eprintln!("next: {}, passes: {}, sign change {}", next, passes, sign_change);
         Counter2(
             Dial(next.rem_euclid(100) as u64),
             count + passes.abs() + sign_change,
@@ -116,7 +112,8 @@ impl Counter2 {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_dial_turn() {
@@ -156,8 +153,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1078, part1(test_input));
+        aoclib::answers::assert_correct::<Day1>(Part::One);
     }
 
     #[test]
@@ -165,16 +161,15 @@ mod tests {
         let mut counter = Counter2(Dial(50), 0);
         let test_input = include_str!("input.test.txt");
         for turn in test_input.lines().map(|l| l.parse::<Turn>().unwrap()) {
-            // println!("{:?} turning {:?}", &counter, &turn);
+            // eprintln!("{:?} turning {:?}", &counter, &turn);
             counter = counter.step(turn);
-            // println!("{:?}", &counter);
+            // eprintln!("{:?}", &counter);
         }
         assert_eq!(6, counter.1);
     }
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(6412, part2(test_input));
+        aoclib::answers::assert_correct::<Day1>(Part::Two);
     }
 }
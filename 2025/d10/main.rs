@@ -3,21 +3,17 @@ use std::{
     fmt::{Debug, Write},
     i64,
     str::FromStr,
-    time::Instant,
     usize,
 };
 
-use aoclib::timing;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day10>();
 }
 
+aoclib::solution!(Day10, 2025, 10);
+
 fn part1(txt: &str) -> i64 {
     let machines: Vec<Machine> = txt.lines().map(|l| l.parse().unwrap()).collect();
     machines
@@ -229,7 +225,7 @@ impl Machine {
             // if i > 10 {
             //     panic!("too high");
             // }
-            // println!("trying buttons of size {} looking for {:?}", i, joltage.0);
+            // eprintln!("trying buttons of size {} looking for {:?}", i, joltage.0);
             for combo in wires.iter().combinations_with_replacement(i as usize) {
                 let mut counts = vec![0; lights.len];
                 for wire in combo {
@@ -244,13 +240,13 @@ impl Machine {
                             counts[i] += 1;
                         }
                     }
-                    // println!(
+                    // eprintln!(
                     //     "{:?} -> {:?} | applying {:?} looking for {:?}",
                     //     state, after, wire, joltage.0
                     // );
                     // state = after;
                 }
-                // println!("[{}] result was {:?}", i, counts);
+                // eprintln!("[{}] result was {:?}", i, counts);
                 if counts == joltage.0 {
                     return i;
                 }
@@ -282,7 +278,8 @@ impl Machine2 {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_parse_all() {
@@ -292,7 +289,7 @@ mod tests {
             .map(|l| l.parse::<Machine>().unwrap())
             .collect();
         for m in ms {
-            println!("{:?}", m);
+            eprintln!("{:?}", m);
         }
     }
 
@@ -394,8 +391,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(517, part1(test_input));
+        aoclib::answers::assert_correct::<Day10>(Part::One);
     }
 
     #[test]
@@ -1,17 +1,14 @@
 use core::str;
-use std::{i64, time::Instant};
+use std::i64;
 
-use aoclib::timing;
 use hashbrown::HashMap;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day11>();
 }
 
+aoclib::solution!(Day11, 2025, 11);
+
 fn part1(txt: &str) -> i64 {
     let connections: HashMap<String, Vec<String>> = txt
         .lines()
@@ -24,7 +21,7 @@ fn part1(txt: &str) -> i64 {
         })
         .collect();
 
-    // println!("connections: {:?}", connections);
+    // eprintln!("connections: {:?}", connections);
 
     let mut memo = HashMap::new();
     memo.insert("out".to_owned(), 1);
@@ -78,7 +75,8 @@ fn count_between(left: String, right: String, connections: &HashMap<String, Vec<
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -88,8 +86,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(413, part1(test_input));
+        aoclib::answers::assert_correct::<Day11>(Part::One);
     }
 
     #[test]
@@ -100,7 +97,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(525518050323600, part2(test_input));
+        aoclib::answers::assert_correct::<Day11>(Part::Two);
     }
 }
@@ -1,17 +1,42 @@
 use core::{fmt, str};
-use std::{fmt::Debug, hash::Hash, str::FromStr, time::Instant, usize};
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    str::FromStr,
+    usize,
+};
 
 use aoclib::{
+    Answer,
     grid::{Flip, FromChar, Grid, GridPosition},
-    input, timing,
+    input,
+    solution::{NoAnswer, Solution},
 };
 use hashbrown::HashSet;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day12>();
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+
+    type Input<'a> = &'a str;
+
+    fn parse(txt: &str) -> Self::Input<'_> {
+        txt
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> impl Into<Answer> {
+        NoAnswer
+    }
 }
 
 fn part1(txt: &str) -> usize {
@@ -56,7 +81,7 @@ impl Present {
 
 impl Hash for Present {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.shape.rows.hash(state);
+        self.shape.hash(state);
     }
 }
 
@@ -181,7 +206,7 @@ impl FromStr for PuzzleInput {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_parse_box_target() {
@@ -211,11 +236,11 @@ mod tests {
     fn test_parse_input_pt1() {
         let test_input = include_str!("input.test.txt");
         let parsed: Result<PuzzleInput, _> = test_input.parse();
-        // println!("{:?}", parsed);
+        // eprintln!("{:?}", parsed);
         assert!(parsed.is_ok());
         // for p in parsed.unwrap().presents {
-        //     println!("{:?}", p.shape);
-        //     println!("{}", p.shape);
+        //     eprintln!("{:?}", p.shape);
+        //     eprintln!("{}", p.shape);
         // }
     }
 }
@@ -1,16 +1,12 @@
 use core::str;
-use std::{i64, str::FromStr, time::Instant, usize};
-
-use aoclib::timing;
+use std::{i64, str::FromStr, usize};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day2>();
 }
 
+aoclib::solution!(Day2, 2025, 2);
+
 fn part1(txt: &str) -> i64 {
     txt.split(",")
         .map(|pair| pair.parse::<ProductRange>().unwrap())
@@ -71,7 +67,7 @@ fn is_symetric_around_half(s: &str, offset: usize) -> bool {
     let front = &s[offset..offset + 1];
     let back = &s[(half_len + offset)..(half_len + offset + 1)];
 
-    // println!("? {front} == {back} ?");
+    // eprintln!("? {front} == {back} ?");
 
     if front == back {
         return is_symetric_around_half(s, offset + 1);
@@ -88,7 +84,7 @@ fn repeats(s: &str) -> bool {
         }
         let max_num_possible_reps = s.len() / (size + 1);
         let mut offsets = 1..max_num_possible_reps;
-        // println!(
+        // eprintln!(
         //     "looking for {:?} in offsets for size {} with max reps {}: {:?}",
         //     seq,
         //     size + 1,
@@ -100,13 +96,13 @@ fn repeats(s: &str) -> bool {
         //     .clone()
         //     .map(|offset| (offset * (size + 1))..((offset * (size + 1)) + size + 1))
         //     .collect_vec();
-        // println!("chunk ranges for size {}: {:?}", size + 1, chunks);
+        // eprintln!("chunk ranges for size {}: {:?}", size + 1, chunks);
 
         // let chunks = offsets
         //     .clone()
         //     .map(|offset| &s[(offset * (size + 1))..((offset * (size + 1)) + size + 1)])
         //     .collect_vec();
-        // println!("chunks for size {}: {:?}", size + 1, chunks);
+        // eprintln!("chunks for size {}: {:?}", size + 1, chunks);
 
         if offsets
             .any(|offset| &s[(offset * (size + 1))..((offset * (size + 1)) + size + 1)] != seq)
@@ -122,7 +118,8 @@ fn repeats(s: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_is_symetric_around_half() {
@@ -148,8 +145,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(34826702005, part1(test_input));
+        aoclib::answers::assert_correct::<Day2>(Part::One);
     }
 
     #[test]
@@ -160,7 +156,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(43287141963, part2(test_input));
+        aoclib::answers::assert_correct::<Day2>(Part::Two);
     }
 }
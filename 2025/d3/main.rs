@@ -1,17 +1,14 @@
 use core::str;
-use std::{str::FromStr, time::Instant};
+use std::str::FromStr;
 
-use aoclib::timing;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day3>();
 }
 
+aoclib::solution!(Day3, 2025, 3);
+
 fn part1(txt: &str) -> usize {
     txt.lines()
         .map(|l| l.parse::<Bank>().expect("Bank"))
@@ -102,7 +99,8 @@ impl Bank {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_bank_parse() {
@@ -145,8 +143,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(16927, part1(test_input));
+        aoclib::answers::assert_correct::<Day3>(Part::One);
     }
 
     #[test]
@@ -157,7 +154,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(167384358365132, part2(test_input));
+        aoclib::answers::assert_correct::<Day3>(Part::Two);
     }
 }
@@ -1,20 +1,14 @@
 use core::str;
-use std::time::Instant;
 
-use aoclib::{
-    grid::{FromChar, Grid, GridPosition},
-    timing,
-};
+use aoclib::grid::{FromChar, Grid, GridPosition};
 use hashbrown::HashSet;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day4>();
 }
 
+aoclib::solution!(Day4, 2025, 4);
+
 fn part1(txt: &str) -> usize {
     let g: Grid<Tile> = txt.parse().unwrap();
     accessible(&g).len()
@@ -42,48 +36,10 @@ fn paper_neighbours(g: &Grid<Tile>, pos: GridPosition) -> HashSet<GridPosition>
     if tile == &Tile::Space {
         return HashSet::new();
     }
-    let mut neighbours = HashSet::new();
-    g.left_from(pos).skip(1).take(1).for_each(|(p, n)| {
-        if n == &Tile::Paper {
-            neighbours.insert(p);
-        }
-    });
-    g.right_from(pos).skip(1).take(1).for_each(|(p, n)| {
-        if n == &Tile::Paper {
-            neighbours.insert(p);
-        }
-    });
-    g.up_from(pos).skip(1).take(1).for_each(|(up_pos, n)| {
-        if n == &Tile::Paper {
-            neighbours.insert(up_pos);
-        }
-        g.left_from(up_pos).skip(1).take(1).for_each(|(p, n)| {
-            if n == &Tile::Paper {
-                neighbours.insert(p);
-            }
-        });
-        g.right_from(up_pos).skip(1).take(1).for_each(|(p, n)| {
-            if n == &Tile::Paper {
-                neighbours.insert(p);
-            }
-        });
-    });
-    g.down_from(pos).skip(1).take(1).for_each(|(down_pos, n)| {
-        if n == &Tile::Paper {
-            neighbours.insert(down_pos);
-        }
-        g.left_from(down_pos).skip(1).take(1).for_each(|(p, n)| {
-            if n == &Tile::Paper {
-                neighbours.insert(p);
-            }
-        });
-        g.right_from(down_pos).skip(1).take(1).for_each(|(p, n)| {
-            if n == &Tile::Paper {
-                neighbours.insert(p);
-            }
-        });
-    });
-    neighbours
+    g.neighbours8(&pos)
+        .filter(|(_, n)| n == &&Tile::Paper)
+        .map(|(p, _)| p)
+        .collect()
 }
 
 fn accessible(g: &Grid<Tile>) -> HashSet<GridPosition> {
@@ -124,7 +80,8 @@ impl FromChar for Tile {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -134,8 +91,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1460, part1(test_input));
+        aoclib::answers::assert_correct::<Day4>(Part::One);
     }
 
     #[test]
@@ -146,7 +102,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(9243, part2(test_input));
+        aoclib::answers::assert_correct::<Day4>(Part::Two);
     }
 }
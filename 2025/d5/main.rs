@@ -1,16 +1,14 @@
 use core::str;
-use std::{i64, str::FromStr, time::Instant, usize};
+use std::{i64, str::FromStr, usize};
 
-use aoclib::{input, timing};
+use aoclib::input;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day5>();
 }
 
+aoclib::solution!(Day5, 2025, 5);
+
 fn part1(txt: &str) -> usize {
     let mut parts = input::empty_line_chunks(txt);
     let ranges: Vec<_> = parts
@@ -121,7 +119,8 @@ impl FreshRange {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -131,8 +130,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(840, part1(test_input));
+        aoclib::answers::assert_correct::<Day5>(Part::One);
     }
 
     #[test]
@@ -180,8 +178,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert!(part2(test_input) < 365266687953826);
-        assert_eq!(359913027576322, part2(test_input));
+        aoclib::answers::assert_correct::<Day5>(Part::Two);
     }
 }
@@ -1,19 +1,14 @@
 use core::str;
-use std::{i64, str::FromStr, time::Instant};
+use std::{i64, str::FromStr};
 
-use aoclib::{
-    grid::{FromChar, Grid, GridPosition},
-    timing,
-};
+use aoclib::grid::{FromChar, Grid, GridPosition};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day6>();
 }
 
+aoclib::solution!(Day6, 2025, 6);
+
 fn part1(txt: &str) -> i64 {
     let grid: Vec<Vec<Col>> = txt
         .lines()
@@ -23,7 +18,7 @@ fn part1(txt: &str) -> i64 {
                 .collect()
         })
         .collect();
-    let g = Grid { rows: grid };
+    let g = Grid::new(grid);
 
     let operations: Vec<_> = g
         .right_from(GridPosition {
@@ -145,7 +140,8 @@ impl FromChar for Col2 {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -155,8 +151,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(6635273135233, part1(test_input));
+        aoclib::answers::assert_correct::<Day6>(Part::One);
     }
 
     #[test]
@@ -167,7 +162,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(12542543681221, part2(test_input));
+        aoclib::answers::assert_correct::<Day6>(Part::Two);
     }
 }
@@ -1,20 +1,15 @@
 use core::str;
-use std::{i64, time::Instant};
+use std::i64;
 
-use aoclib::{
-    grid::{FromChar, Grid, GridPosition},
-    timing,
-};
+use aoclib::grid::{FromChar, Grid, GridPosition};
 use hashbrown::{HashMap, HashSet};
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day7>();
 }
 
+aoclib::solution!(Day7, 2025, 7);
+
 fn part1(txt: &str) -> i64 {
     let g: Grid<Tile> = txt.parse().unwrap();
     let (p, _start) = g.position_itr().find(|(_, t)| t == &&Tile::Start).unwrap();
@@ -103,7 +98,8 @@ impl FromChar for Tile {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -113,8 +109,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1585, part1(test_input));
+        aoclib::answers::assert_correct::<Day7>(Part::One);
     }
 
     #[test]
@@ -125,7 +120,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(16716444407407, part2(test_input));
+        aoclib::answers::assert_correct::<Day7>(Part::Two);
     }
 }
@@ -1,18 +1,16 @@
 use core::str;
-use std::{i64, time::Instant, usize};
+use std::{i64, usize};
 
-use aoclib::timing;
+use aoclib::cartesian3::Point3;
 use hashbrown::HashSet;
 use itertools::Itertools;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day8>();
 }
 
+aoclib::solution!(Day8, 2025, 8);
+
 fn part1(txt: &str) -> usize {
     connections(txt, 1000 - 1)
 }
@@ -64,32 +62,24 @@ fn report(all_circuits: &Vec<Circuit>) -> usize {
     chosen.iter().map(|c| c.junction_boxes.len()).product()
 }
 
-fn distances(all_points: &Vec<Position>) -> Vec<Distance> {
+fn distances(all_points: &Vec<Point3>) -> Vec<Distance> {
     all_points
         .iter()
         .combinations(2)
         .map(|combo| {
             let left = combo[0];
             let right = combo[1];
-            left.distance(right)
+            distance(left, right)
         })
         .sorted_by(|a, b| a.squared_euclidean.cmp(&b.squared_euclidean))
         .collect()
 }
 
-fn all_points(txt: &str) -> Vec<Position> {
-    txt.lines()
-        .map(|l| {
-            let mut points = l.split(",");
-            let x = points.next().expect("x").parse().unwrap();
-            let y = points.next().expect("y").parse().unwrap();
-            let z = points.next().expect("z").parse().unwrap();
-            Position { x: x, y, z }
-        })
-        .collect()
+fn all_points(txt: &str) -> Vec<Point3> {
+    txt.lines().map(|l| l.parse().expect("x,y,z")).collect()
 }
 
-fn merge(closest_pair: Distance, all_circuits: &mut Vec<Circuit>) -> (Position, Position) {
+fn merge(closest_pair: Distance, all_circuits: &mut Vec<Circuit>) -> (Point3, Point3) {
     let (left, right) = closest_pair.positions;
     let removed_left = {
         if let Some((p, _)) = all_circuits
@@ -121,52 +111,41 @@ fn merge(closest_pair: Distance, all_circuits: &mut Vec<Circuit>) -> (Position,
     } else if removed_left.is_some() {
         // add right to left circuit
         let mut rl = removed_left.unwrap();
-        rl.junction_boxes.insert(right.clone());
+        rl.junction_boxes.insert(right);
         all_circuits.push(rl);
     } else if removed_right.is_some() {
         // add left to right circuit
         let mut rr = removed_right.unwrap();
-        rr.junction_boxes.insert(left.clone());
+        rr.junction_boxes.insert(left);
         all_circuits.push(rr);
     } else {
         // doesn't exist so create new
-        all_circuits.push(Circuit::new(left.clone(), right.clone()));
+        all_circuits.push(Circuit::new(left, right));
     }
 
     (left, right)
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-struct Position {
-    x: i64,
-    y: i64,
-    z: i64,
-}
-
-impl Position {
-    fn distance(&self, other: &Self) -> Distance {
-        Distance {
-            positions: (self.clone(), other.clone()),
-            squared_euclidean: i64::pow(self.x - other.x, 2)
-                + i64::pow(self.y - other.y, 2)
-                + i64::pow(self.z - other.z, 2),
-        }
+fn distance(left: &Point3, right: &Point3) -> Distance {
+    Distance {
+        positions: (*left, *right),
+        squared_euclidean: left.squared_distance(right),
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Distance {
-    positions: (Position, Position),
+    positions: (Point3, Point3),
     squared_euclidean: i64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Circuit {
-    junction_boxes: HashSet<Position>,
+    junction_boxes: HashSet<Point3>,
 }
 
 impl Circuit {
-    fn new(left: Position, right: Position) -> Self {
+    fn new(left: Point3, right: Point3) -> Self {
         let mut junction_boxes = HashSet::new();
         junction_boxes.insert(left);
         junction_boxes.insert(right);
@@ -176,7 +155,8 @@ impl Circuit {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_input_pt1() {
@@ -186,8 +166,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(42315, part1(test_input));
+        aoclib::answers::assert_correct::<Day8>(Part::One);
     }
 
     #[test]
@@ -198,7 +177,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(8079278220, part2(test_input));
+        aoclib::answers::assert_correct::<Day8>(Part::Two);
     }
 }
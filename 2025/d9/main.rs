@@ -1,19 +1,16 @@
 use core::str;
-use std::{i64, str::FromStr, time::Instant};
+use std::{i64, str::FromStr};
 
-use aoclib::timing;
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 use rayon::prelude::*;
 
 fn main() {
-    let input = include_str!("input.txt");
-    let now = Instant::now();
-    println!("part1: {}", part1(input));
-    println!("part2: {}", part2(input));
-    println!("{}", timing::format_elapsed_time(now.elapsed()));
+    aoclib::solution::main::<Day9>();
 }
 
+aoclib::solution!(Day9, 2025, 9);
+
 fn part1(txt: &str) -> i64 {
     let tiles: Vec<Point> = txt.lines().map(|l| l.parse::<Point>().unwrap()).collect();
     tiles
@@ -126,12 +123,12 @@ fn print_points(green: &HashSet<Point>, rows: i64, cols: i64) {
         for j in 0..cols {
             let p = Point { x: j, y: i };
             if green.contains(&p) {
-                print!("X")
+                eprint!("X")
             } else {
-                print!(".")
+                eprint!(".")
             }
         }
-        println!("");
+        eprintln!("");
     }
 }
 
@@ -274,7 +271,8 @@ impl FromStr for Point {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoclib::solution::Part;
 
     #[test]
     fn test_area() {
@@ -329,8 +327,7 @@ mod tests {
 
     #[test]
     fn input_pt1() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(4754955192, part1(test_input));
+        aoclib::answers::assert_correct::<Day9>(Part::One);
     }
 
     #[test]
@@ -341,7 +338,6 @@ mod tests {
 
     #[test]
     fn input_pt2() {
-        let test_input = include_str!("input.txt");
-        assert_eq!(1568849600, part2(test_input));
+        aoclib::answers::assert_correct::<Day9>(Part::Two);
     }
 }
@@ -0,0 +1,290 @@
+//! Runs many days at once on a thread pool, isolating each from the others'
+//! panics and infinite loops.
+
+use std::{
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoclib::solution::{Part, Run, Runnable, panic_message};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Days still running after this are reported as timed out and left behind
+    pub timeout: Duration,
+    /// Threads in the pool
+    pub jobs: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            jobs: thread::available_parallelism().map_or(4, |n| n.get()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Run),
+    Panicked(String),
+    TimedOut(Duration),
+    /// Every thread in the pool was stuck on a timed out day for a whole timeout, so
+    /// this one never started
+    NotRun,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub outcome: Outcome,
+}
+
+enum Event {
+    Started(usize, Instant),
+    Finished(usize, Outcome),
+}
+
+/// Solves every day against its puzzle text, returning a report per day in the order given.
+///
+/// A day that runs past the timeout is abandoned: its thread can't be stopped so it
+/// keeps spinning until it finishes or the process exits, and the pool has one thread
+/// fewer for the rest until then. Its late answer is ignored.
+pub fn run_all(days: Vec<(&'static dyn Runnable, String)>, options: &Options) -> Vec<Report> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs)
+        .build()
+        .expect("thread pool");
+    let (tx, rx) = mpsc::channel();
+
+    let mut reports: Vec<Report> = days
+        .iter()
+        .map(|(solution, _)| Report {
+            year: solution.year(),
+            day: solution.day(),
+            outcome: Outcome::NotRun,
+        })
+        .collect();
+
+    for (i, (solution, txt)) in days.into_iter().enumerate() {
+        let tx = tx.clone();
+        pool.spawn(move || {
+            // the receiver is gone once everything left has timed out
+            let _ = tx.send(Event::Started(i, Instant::now()));
+            let outcome =
+                match panic::catch_unwind(AssertUnwindSafe(|| solution.run(&txt, &Part::ALL))) {
+                    Ok(run) => Outcome::Solved(run),
+                    Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+                };
+            let _ = tx.send(Event::Finished(i, outcome));
+        });
+    }
+    drop(tx);
+
+    let mut running: HashMap<usize, Instant> = HashMap::new();
+    let mut pending = reports.len();
+    let mut stuck = 0;
+
+    while pending > 0 {
+        let next_deadline = running
+            .values()
+            .map(|started| *started + options.timeout)
+            .min();
+        let wait = next_deadline.map_or(options.timeout, |deadline| {
+            deadline.saturating_duration_since(Instant::now())
+        });
+
+        match rx.recv_timeout(wait) {
+            Ok(Event::Started(i, started)) => {
+                running.insert(i, started);
+            }
+            Ok(Event::Finished(i, outcome)) => {
+                if running.remove(&i).is_some() {
+                    reports[i].outcome = outcome;
+                    pending -= 1;
+                } else {
+                    // a timed out day finally gave its thread back
+                    stuck -= 1;
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let timed_out: Vec<usize> = running
+                    .iter()
+                    .filter(|(_, started)| now.duration_since(**started) >= options.timeout)
+                    .map(|(i, _)| *i)
+                    .collect();
+                if timed_out.is_empty() && stuck >= options.jobs {
+                    break;
+                }
+                for i in timed_out {
+                    running.remove(&i);
+                    reports[i].outcome = Outcome::TimedOut(options.timeout);
+                    pending -= 1;
+                    stuck += 1;
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    reports
+}
+
+#[cfg(test)]
+mod tests {
+    use aoclib::{Answer, solution::Solution};
+
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 1;
+
+        type Input<'a> = &'a str;
+
+        fn parse(txt: &str) -> Self::Input<'_> {
+            txt
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+            input.lines().count()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+            input.len()
+        }
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 2;
+
+        type Input<'a> = &'a str;
+
+        fn parse(txt: &str) -> Self::Input<'_> {
+            txt
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+            assert!(!input.is_empty(), "bad input");
+            input.len()
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> impl Into<Answer> {
+            0
+        }
+    }
+
+    struct Loops;
+
+    impl Solution for Loops {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 3;
+
+        type Input<'a> = ();
+
+        fn parse(_txt: &str) -> Self::Input<'_> {}
+
+        fn part1(_input: &Self::Input<'_>) -> impl Into<Answer> {
+            std::iter::repeat_with(|| thread::sleep(Duration::from_millis(10))).count()
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> impl Into<Answer> {
+            0
+        }
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 4;
+
+        type Input<'a> = ();
+
+        fn parse(_txt: &str) -> Self::Input<'_> {}
+
+        fn part1(_input: &Self::Input<'_>) -> impl Into<Answer> {
+            thread::sleep(Duration::from_millis(150));
+            0
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> impl Into<Answer> {
+            0
+        }
+    }
+
+    fn answers(report: &Report) -> Vec<String> {
+        match &report.outcome {
+            Outcome::Solved(run) => run.parts.iter().map(|p| p.answer.to_string()).collect(),
+            other => panic!("not solved: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_isolates_failures() {
+        let options = Options {
+            timeout: Duration::from_millis(200),
+            jobs: 2,
+        };
+        let reports = run_all(
+            vec![
+                (&Loops, String::new()),
+                (&Panics, String::new()),
+                (&Lines, "a\nb".to_owned()),
+            ],
+            &options,
+        );
+
+        assert_eq!(
+            vec![3, 2, 1],
+            reports.iter().map(|r| r.day).collect::<Vec<_>>()
+        );
+        assert_eq!(Outcome::TimedOut(options.timeout), reports[0].outcome);
+        assert_eq!(
+            Outcome::Panicked("bad input".to_owned()),
+            reports[1].outcome
+        );
+        assert_eq!(vec!["2", "3"], answers(&reports[2]));
+    }
+
+    #[test]
+    fn test_stuck_pool() {
+        let options = Options {
+            timeout: Duration::from_millis(100),
+            jobs: 1,
+        };
+        let reports = run_all(
+            vec![(&Loops, String::new()), (&Lines, String::new())],
+            &options,
+        );
+
+        assert_eq!(Outcome::TimedOut(options.timeout), reports[0].outcome);
+        assert_eq!(Outcome::NotRun, reports[1].outcome);
+    }
+
+    #[test]
+    fn test_late_finish_frees_thread() {
+        let options = Options {
+            timeout: Duration::from_millis(100),
+            jobs: 1,
+        };
+        let reports = run_all(
+            vec![(&Slow, String::new()), (&Lines, "a".to_owned())],
+            &options,
+        );
+
+        assert_eq!(Outcome::TimedOut(options.timeout), reports[0].outcome);
+        assert_eq!(vec!["1", "1"], answers(&reports[1]));
+    }
+}
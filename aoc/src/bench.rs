@@ -0,0 +1,133 @@
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+
+use aoclib::{
+    bench::{self, Baseline, Options},
+    input,
+};
+
+const USAGE: &str = "\
+usage: aoc-bench [<year> [<day>]] [--iterations N] [--max-time SECS]
+                 [--threshold PERCENT] [--baseline <path>] [--save]";
+
+struct Args {
+    year: Option<u16>,
+    day: Option<u8>,
+    options: Options,
+    threshold: f64,
+    baseline: PathBuf,
+    save: bool,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    match parse_args(&args).and_then(|args| run(&args)) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[&str]) -> Result<Args, String> {
+    let mut parsed = Args {
+        year: None,
+        day: None,
+        options: Options::default(),
+        threshold: 0.1,
+        baseline: Baseline::default_path(),
+        save: false,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(USAGE.to_owned());
+        match *arg {
+            "--iterations" => {
+                parsed.options.iterations = value()?.parse().map_err(|_| USAGE.to_owned())?
+            }
+            "--max-time" => {
+                let secs: f64 = value()?.parse().map_err(|_| USAGE.to_owned())?;
+                parsed.options.max_time = Duration::from_secs_f64(secs);
+            }
+            "--threshold" => {
+                let percent: f64 = value()?.parse().map_err(|_| USAGE.to_owned())?;
+                parsed.threshold = percent / 100.0;
+            }
+            "--baseline" => parsed.baseline = value()?.into(),
+            "--save" => parsed.save = true,
+            year if parsed.year.is_none() => {
+                parsed.year = Some(year.parse().map_err(|_| format!("bad year '{year}'"))?)
+            }
+            day if parsed.day.is_none() => {
+                let day = day
+                    .trim_start_matches('d')
+                    .parse()
+                    .map_err(|_| format!("bad day '{day}'"))?;
+                parsed.day = Some(day)
+            }
+            _ => return Err(USAGE.to_owned()),
+        }
+    }
+    Ok(parsed)
+}
+
+/// Benchmarks every selected day, returning whether all kept up with the baseline
+fn run(args: &Args) -> Result<bool, String> {
+    let mut baseline = Baseline::load(&args.baseline)
+        .map_err(|e| format!("can't read {}: {e}", args.baseline.display()))?;
+
+    let solutions: Vec<_> = aoc::solutions()
+        .filter(|s| args.year.is_none_or(|y| s.year() == y))
+        .filter(|s| args.day.is_none_or(|d| s.day() == d))
+        .collect();
+    if solutions.is_empty() {
+        return Err(match (args.year, args.day) {
+            (Some(year), Some(day)) => aoc::not_found(year, day),
+            _ => "no solutions registered to bench".to_owned(),
+        });
+    }
+
+    let mut regressions = vec![];
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let txt = match input::load(year, day) {
+            Ok(txt) => txt,
+            Err(e) => {
+                eprintln!("skipping {year} day {day}: {e}");
+                continue;
+            }
+        };
+
+        let result = bench::bench(solution, &txt, &args.options);
+        println!("{year} day {day} ({} iterations)", result.iterations);
+        for (stage, stats) in result.stages() {
+            println!("  {stage:<6} {stats}");
+        }
+
+        if let Some(before) = baseline.get(year, day) {
+            for regression in result.regressions(before, args.threshold) {
+                println!("  {regression}");
+                regressions.push(regression);
+            }
+        }
+        if args.save {
+            baseline.record(result);
+        }
+    }
+
+    if args.save {
+        baseline
+            .save(&args.baseline)
+            .map_err(|e| format!("can't write {}: {e}", args.baseline.display()))?;
+        println!("saved baseline to {}", args.baseline.display());
+    }
+
+    if !regressions.is_empty() {
+        eprintln!("{} stage(s) regressed", regressions.len());
+    }
+    Ok(regressions.is_empty())
+}
@@ -0,0 +1,115 @@
+use std::{fs, path::Path, process::ExitCode};
+
+use aoc::puzzle::PuzzlePage;
+use aoclib::{
+    examples::{Expected, expected_path},
+    input,
+};
+
+const USAGE: &str = "usage: aoc-examples <page.html> [<year> <day>] [--force]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    match extract(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Saves the examples from a puzzle page beside the day's solution, along
+/// with the answers the first one probably expects
+fn extract(args: &[&str]) -> Result<(), String> {
+    let force = args.contains(&"--force");
+    let args: Vec<&str> = args.iter().copied().filter(|a| *a != "--force").collect();
+    let (page, year_day) = match args.as_slice() {
+        [page] => (page, None),
+        [page, year, day] => {
+            let year = year.parse().map_err(|_| format!("bad year '{year}'"))?;
+            let day = day
+                .trim_start_matches('d')
+                .parse()
+                .map_err(|_| format!("bad day '{day}'"))?;
+            (page, Some((year, day)))
+        }
+        _ => return Err(USAGE.to_owned()),
+    };
+
+    let html = fs::read_to_string(page).map_err(|e| format!("can't read {page}: {e}"))?;
+    let page = PuzzlePage::new(&html);
+    let (year, day) = year_day
+        .or_else(|| page.year_day())
+        .ok_or("can't tell the year and day from the page, give them")?;
+
+    let dir = input::day_dir(year, day);
+    if !dir.is_dir() {
+        return Err(format!(
+            "no {}, create the day with aoc-new first",
+            dir.display()
+        ));
+    }
+
+    let examples = page.examples();
+    if examples.is_empty() {
+        return Err("no <pre><code> examples on the page".to_owned());
+    }
+    let mut paths = vec![];
+    for (i, example) in examples.iter().enumerate() {
+        let name = match i {
+            0 => "input.test.txt".to_owned(),
+            n => format!("input.test.{}.txt", n + 1),
+        };
+        let path = dir.join(name);
+        let saved = save(&path, example, force)?;
+        paths.push((path, saved));
+    }
+
+    let mut expected = Expected::default();
+    for (part, candidates) in page.answer_candidates() {
+        let Some((likely, others)) = candidates.split_first() else {
+            continue;
+        };
+        match others {
+            [] => println!("{part}: probably {likely}"),
+            _ => println!("{part}: probably {likely}, otherwise one of {others:?}"),
+        }
+        expected.record(part, likely.as_str());
+    }
+    if !expected.is_empty() {
+        // the page doesn't say which example an answer is for, it's usually the first
+        let (first, saved) = &paths[0];
+        if *saved {
+            let guessed =
+                format!("# Guessed by aoc-examples from the puzzle page, check them.\n{expected}");
+            save(&expected_path(first), &guessed, force)?;
+        } else {
+            println!("not guessing answers for the kept {}", first.display());
+        }
+    }
+    Ok(())
+}
+
+/// Writes a file, leaving any already there alone unless `force`d. An empty
+/// file, like the one `aoc-new` leaves, counts as not being there.
+///
+/// Returns whether the file now holds `contents`, rather than being kept.
+fn save(path: &Path, contents: &str, force: bool) -> Result<bool, String> {
+    let existing = fs::read_to_string(path).ok().filter(|e| !e.is_empty());
+    match existing {
+        Some(existing) if existing == contents => println!("unchanged {}", path.display()),
+        Some(_) if !force => {
+            println!("kept {}, pass --force to replace it", path.display());
+            return Ok(false);
+        }
+        _ => {
+            fs::write(path, contents)
+                .map_err(|e| format!("can't write {}: {e}", path.display()))?;
+            println!("wrote {}", path.display());
+        }
+    }
+    Ok(true)
+}
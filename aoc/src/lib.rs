@@ -0,0 +1,59 @@
+use aoclib::solution::Runnable;
+
+pub mod batch;
+pub mod puzzle;
+pub mod scaffold;
+
+/// Every registered day across all years, ordered by year then day.
+pub fn solutions() -> impl Iterator<Item = &'static dyn Runnable> {
+    [
+        aoc2020::SOLUTIONS,
+        aoc2021::SOLUTIONS,
+        aoc2022::SOLUTIONS,
+        aoc2023::SOLUTIONS,
+        aoc2024::SOLUTIONS,
+        aoc2025::SOLUTIONS,
+    ]
+    .into_iter()
+    .flatten()
+    .copied()
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Runnable> {
+    solutions().find(|s| s.year() == year && s.day() == day)
+}
+
+/// Why [`find`] came up empty
+pub fn not_found(year: u16, day: u8) -> String {
+    format!("no solution registered for {year} day {day}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solutions_are_ordered_and_unique() {
+        let keys: Vec<_> = solutions().map(|s| (s.year(), s.day())).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, keys);
+    }
+
+    #[test]
+    fn test_find() {
+        let found = find(2024, 17).unwrap();
+        assert_eq!((2024, 17), (found.year(), found.day()));
+        assert!(find(2024, 26).is_none());
+    }
+
+    #[test]
+    fn test_not_found() {
+        assert!(find(2021, 4).is_some());
+        assert_eq!(
+            "no solution registered for 2024 day 26",
+            not_found(2024, 26)
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use std::{collections::HashMap, panic, process::ExitCode, time::Duration};
+
+use aoc::batch::{self, Outcome};
+use aoclib::{
+    Answer,
+    answers::{Answers, Verdict},
+    input,
+    solution::{Part, Run},
+    timing::{self, Format, Record},
+};
+use itertools::Itertools;
+
+const USAGE: &str = "\
+usage: aoc run <year> <day> [--part N] [--input <path>] [--format F]
+       aoc all [<year>] [--timeout SECS] [--jobs N] [--format F]
+       aoc accept <year> <day> <part> [<answer>]
+       aoc list
+
+F is text, json or csv, and defaults to $AOC_OUTPUT";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    let result = match args.as_slice() {
+        ["list"] => {
+            list();
+            Ok(())
+        }
+        ["run", year, day, rest @ ..] => run(year, day, rest),
+        ["all", rest @ ..] => all(rest),
+        ["accept", year, day, part] => accept(year, day, part, None),
+        ["accept", year, day, part, answer] => accept(year, day, part, Some(answer)),
+        _ => Err(USAGE.to_owned()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list() {
+    for (year, days) in &aoc::solutions().group_by(|s| s.year()) {
+        println!("{year}: {}", days.map(|s| s.day()).join(" "));
+    }
+}
+
+fn parse_day(year: &str, day: &str) -> Result<(u16, u8), String> {
+    let year: u16 = year.parse().map_err(|_| format!("bad year '{year}'"))?;
+    let day: u8 = day
+        .trim_start_matches('d')
+        .parse()
+        .map_err(|_| format!("bad day '{day}'"))?;
+    Ok((year, day))
+}
+
+fn run(year: &str, day: &str, rest: &[&str]) -> Result<(), String> {
+    let (year, day) = parse_day(year, day)?;
+    let mut parts = Part::ALL.to_vec();
+    let mut path = None;
+    let mut format = Format::from_env()?;
+    for flag in rest.chunks(2) {
+        match flag {
+            ["--part", n] => parts = vec![n.parse()?],
+            ["--input", p] => path = Some(p.into()),
+            ["--format", f] => format = Format::parse(f)?,
+            _ => return Err(USAGE.to_owned()),
+        }
+    }
+
+    let solution = aoc::find(year, day).ok_or_else(|| aoc::not_found(year, day))?;
+    // answers only apply to the real puzzle input
+    let path = path.or_else(input::override_path);
+    let answers = match path {
+        Some(_) => None,
+        None => Some(Answers::load(year).map_err(|e| e.to_string())?),
+    };
+    let path = path.unwrap_or_else(|| input::default_path(year, day));
+    let txt = input::read(&path).map_err(|e| e.to_string())?;
+
+    let result = solution.run(&txt, &parts);
+    if let Some(format) = format {
+        timing::print_records(format, &Record::from_run(&result, answers.as_ref()));
+        return Ok(());
+    }
+
+    println!("{year} day {day}");
+    for p in &result.parts {
+        let elapsed = timing::format_elapsed_time(p.elapsed);
+        let verdict = answers
+            .as_ref()
+            .map(|a| format!(", {}", a.check(day, p.part, &p.answer)))
+            .unwrap_or_default();
+        if p.answer.is_multiline() {
+            println!("{}: ({elapsed}{verdict})\n{}", p.part, p.answer);
+        } else {
+            println!("{}: {} ({elapsed}{verdict})", p.part, p.answer);
+        }
+    }
+    println!("parse: {}", timing::format_elapsed_time(result.parse));
+    println!("total: {}", timing::format_elapsed_time(result.elapsed()));
+    Ok(())
+}
+
+/// Runs every day of a year, or of every year, in parallel and tabulates the results
+fn all(args: &[&str]) -> Result<(), String> {
+    let mut year = None;
+    let mut options = batch::Options::default();
+    let mut format = Format::from_env()?;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--timeout" => {
+                let secs: f64 = args.next().and_then(|s| s.parse().ok()).ok_or(USAGE)?;
+                options.timeout = Duration::from_secs_f64(secs);
+            }
+            "--jobs" => {
+                options.jobs = args
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or(USAGE)?
+            }
+            "--format" => format = Format::parse(args.next().ok_or(USAGE)?)?,
+            y if year.is_none() => {
+                year = Some(y.parse::<u16>().map_err(|_| format!("bad year '{y}'"))?)
+            }
+            _ => return Err(USAGE.to_owned()),
+        }
+    }
+
+    let mut rows = vec![];
+    let mut days = vec![];
+    for solution in aoc::solutions().filter(|s| year.is_none_or(|y| s.year() == y)) {
+        match input::load(solution.year(), solution.day()) {
+            Ok(txt) => days.push((solution, txt)),
+            Err(_) => rows.push(Row {
+                // not a failure, there's just nothing to check yet
+                failed: false,
+                ..Row::failed(solution.year(), solution.day(), "no input")
+            }),
+        }
+    }
+    if days.is_empty() && rows.is_empty() {
+        return Err("no solutions registered".to_owned());
+    }
+
+    // panics are reported in the table instead
+    panic::set_hook(Box::new(|_| {}));
+    let reports = batch::run_all(days, &options);
+    let _ = panic::take_hook();
+
+    let mut answers = HashMap::new();
+    let mut total = Duration::ZERO;
+    for report in reports {
+        let (year, day) = (report.year, report.day);
+        let row = match report.outcome {
+            Outcome::Solved(run) => {
+                total += run.elapsed();
+                let answers = answers
+                    .entry(year)
+                    .or_insert_with(|| Answers::load(year).unwrap_or_default());
+                Row::solved(&run, answers)
+            }
+            Outcome::Panicked(message) => {
+                let message = message.lines().next().unwrap_or_default();
+                Row::failed(year, day, &format!("panicked: {message}"))
+            }
+            Outcome::TimedOut(after) => Row::failed(
+                year,
+                day,
+                &format!("timed out after {}", timing::format_elapsed_time(after)),
+            ),
+            Outcome::NotRun => Row::failed(year, day, "not run, every thread was stuck"),
+        };
+        rows.push(row);
+    }
+    rows.sort_by_key(|row| (row.year, row.day));
+    let failed = rows.iter().filter(|r| r.failed).count();
+    let result = match failed {
+        0 => Ok(()),
+        n => Err(format!("{n} day(s) failed")),
+    };
+
+    if let Some(format) = format {
+        let records: Vec<Record> = rows.into_iter().flat_map(|r| r.records).collect();
+        timing::print_records(format, &records);
+        return result;
+    }
+
+    let width = |cell: fn(&Row) -> &str, title: &str| {
+        rows.iter()
+            .map(|r| cell(r).chars().count())
+            .chain([title.len()])
+            .max()
+            .unwrap_or(0)
+    };
+    let w1 = width(|r| &r.part1, "part1");
+    let w2 = width(|r| &r.part2, "part2");
+    let w3 = width(|r| &r.time, "time");
+    println!(
+        "year  day  {:<w1$}  {:<w2$}  {:>w3$}  status",
+        "part1", "part2", "time"
+    );
+    for row in &rows {
+        println!(
+            "{}  {:>3}  {:<w1$}  {:<w2$}  {:>w3$}  {}",
+            row.year, row.day, row.part1, row.part2, row.time, row.status
+        );
+    }
+
+    println!(
+        "{} of {} days solved in {}",
+        rows.iter()
+            .filter(|r| !r.time.is_empty() && !r.failed)
+            .count(),
+        rows.len(),
+        timing::format_elapsed_time(total)
+    );
+    result
+}
+
+/// A line of the table printed by `aoc all`
+struct Row {
+    year: u16,
+    day: u8,
+    part1: String,
+    part2: String,
+    time: String,
+    status: String,
+    failed: bool,
+    records: Vec<Record>,
+}
+
+impl Row {
+    fn solved(run: &Run, answers: &Answers) -> Self {
+        let verdicts: Vec<_> = run
+            .parts
+            .iter()
+            .map(|p| answers.check(run.day, p.part, &p.answer))
+            .collect();
+        let wrong = verdicts.iter().any(|v| matches!(v, Verdict::Wrong { .. }));
+        let status = if wrong {
+            "wrong"
+        } else if verdicts.iter().all(|v| *v == Verdict::Correct) {
+            "correct"
+        } else {
+            "unknown"
+        };
+
+        Self {
+            year: run.year,
+            day: run.day,
+            part1: cell(&run.parts[0].answer),
+            part2: cell(&run.parts[1].answer),
+            time: timing::format_elapsed_time(run.elapsed()),
+            status: status.to_owned(),
+            failed: wrong,
+            records: Record::from_run(run, Some(answers)),
+        }
+    }
+
+    fn failed(year: u16, day: u8, status: &str) -> Self {
+        Self {
+            year,
+            day,
+            part1: String::new(),
+            part2: String::new(),
+            time: String::new(),
+            status: status.to_owned(),
+            failed: true,
+            records: vec![Record::failed(year, day, status)],
+        }
+    }
+}
+
+/// An answer short enough for a table cell
+fn cell(answer: &Answer) -> String {
+    let text = answer.to_string();
+    match text.lines().count() {
+        0 | 1 => text,
+        n => format!("({n} lines)"),
+    }
+}
+
+/// Records `answer` as accepted, solving the puzzle input for it when not given
+fn accept(year: &str, day: &str, part: &str, answer: Option<&str>) -> Result<(), String> {
+    let (year, day) = parse_day(year, day)?;
+    let part: Part = part.parse()?;
+
+    let answer = match answer {
+        Some(answer) => answer.to_owned(),
+        None => {
+            let solution = aoc::find(year, day).ok_or_else(|| aoc::not_found(year, day))?;
+            let txt = input::load(year, day).map_err(|e| e.to_string())?;
+            solution
+                .run(&txt, &[part])
+                .parts
+                .remove(0)
+                .answer
+                .to_string()
+        }
+    };
+
+    let mut answers = Answers::load(year).map_err(|e| e.to_string())?;
+    match answers.record(day, part, answer.clone()) {
+        Some(previous) if previous == answer => {
+            println!("{year} day {day} {part} already accepted: {answer}");
+            return Ok(());
+        }
+        Some(previous) => println!("{year} day {day} {part}: {previous} -> {answer}"),
+        None => println!("{year} day {day} {part}: {answer}"),
+    }
+    answers.save(year).map_err(|e| e.to_string())
+}
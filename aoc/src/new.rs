@@ -0,0 +1,60 @@
+use std::process::ExitCode;
+
+use aoc::scaffold::{Template, Workspace};
+use aoclib::input;
+
+const USAGE: &str = "usage: aoc-new [<year> [<day>]] [--template grid|graph|plain]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    match new(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new(args: &[&str]) -> Result<(), String> {
+    let workspace = Workspace::new(input::workspace_root());
+
+    let mut template = Template::default();
+    let mut numbers = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--template" => template = args.next().ok_or(USAGE)?.parse()?,
+            n => numbers.push(n),
+        }
+    }
+
+    let (year, day) = match numbers.as_slice() {
+        [] => {
+            let year = *workspace
+                .years()
+                .last()
+                .ok_or("no years yet, give a year")?;
+            (year, workspace.next_day(year))
+        }
+        [year] => {
+            let year = year.parse().map_err(|_| format!("bad year '{year}'"))?;
+            (year, workspace.next_day(year))
+        }
+        [year, day] => (
+            year.parse().map_err(|_| format!("bad year '{year}'"))?,
+            day.trim_start_matches('d')
+                .parse()
+                .map_err(|_| format!("bad day '{day}'"))?,
+        ),
+        _ => return Err(USAGE.to_owned()),
+    };
+
+    for path in workspace.create_day(year, day, template)? {
+        println!("created {}", path.display());
+    }
+    println!("registered {year} day {day}, run it with `cargo run --bin d{day} -p aoc{year}`");
+    Ok(())
+}
@@ -0,0 +1,166 @@
+//! Pulls the examples, and likely answers to them, out of a saved puzzle page.
+//!
+//! Only the handful of tags the puzzle pages use are understood, this is no
+//! general HTML parser.
+
+use aoclib::solution::Part;
+
+/// A puzzle page as saved from the browser
+pub struct PuzzlePage<'a> {
+    html: &'a str,
+}
+
+impl<'a> PuzzlePage<'a> {
+    pub fn new(html: &'a str) -> Self {
+        Self { html }
+    }
+
+    /// The year and day from the page title, eg: `Day 17 - Advent of Code 2024`
+    pub fn year_day(&self) -> Option<(u16, u8)> {
+        let title = between(self.html, "<title>", "</title>").next()?;
+        let (day, year) = title
+            .strip_prefix("Day ")?
+            .split_once(" - Advent of Code ")?;
+        Some((year.trim().parse().ok()?, day.trim().parse().ok()?))
+    }
+
+    /// The text of every `<pre><code>` block, in page order
+    pub fn examples(&self) -> Vec<String> {
+        between(self.html, "<pre><code>", "</code></pre>")
+            .map(text)
+            .collect()
+    }
+
+    /// The emphasised code from each part's description, the most likely
+    /// answer first: it's usually the last one mentioned.
+    ///
+    /// Part two only appears once part one has been solved.
+    pub fn answer_candidates(&self) -> Vec<(Part, Vec<String>)> {
+        between(self.html, "<article", "</article>")
+            .zip(Part::ALL)
+            .map(|(article, part)| {
+                let mut emphasised: Vec<(usize, &str)> =
+                    positioned(article, "<code><em>", "</em></code>")
+                        .chain(positioned(article, "<em><code>", "</code></em>"))
+                        .collect();
+                // the two spellings are found separately, so put them back in page order
+                emphasised.sort();
+
+                let mut candidates: Vec<String> = vec![];
+                for (_, html) in emphasised.into_iter().rev() {
+                    let candidate = text(html);
+                    if !candidates.contains(&candidate) {
+                        candidates.push(candidate);
+                    }
+                }
+                (part, candidates)
+            })
+            .collect()
+    }
+}
+
+/// The slices of `html` between each `start` and the following `end`
+fn between<'a>(html: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    positioned(html, start, end).map(|(_, s)| s)
+}
+
+/// [`between`] along with where each slice starts
+fn positioned<'a>(
+    html: &'a str,
+    start: &'a str,
+    end: &'a str,
+) -> impl Iterator<Item = (usize, &'a str)> {
+    html.match_indices(start).filter_map(move |(i, _)| {
+        let from = i + start.len();
+        let len = html[from..].find(end)?;
+        Some((from, &html[from..from + len]))
+    })
+}
+
+/// `html` without its tags, with entities decoded
+fn text(html: &str) -> String {
+    let mut result = String::new();
+    let mut rest = html;
+    while let Some(open) = rest.find('<') {
+        result.push_str(&rest[..open]);
+        rest = rest[open..].split_once('>').map_or("", |(_, after)| after);
+    }
+    result.push_str(rest);
+
+    result
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head>
+<title>Day 17 - Advent of Code 2024</title>
+</head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 17: Chronospatial Computer ---</h2>
+<p>For example:</p>
+<pre><code>Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+</code></pre>
+<p>If register <code>C</code> contains <code>9</code>, the program <code>2,6</code> would set register <code>B</code> to <code><em>1</em></code>.</p>
+<p>A &lt; B &amp;&amp; <code>x</code></p>
+<pre><code>a &lt;<em>b</em>&gt;
+</code></pre>
+<p>Its final output is <code><em>4,6,3,5,6,3,5,2,1,0</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>7,3,0,5,7,1,4,0,5</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>This program returns a copy of itself if register <code>A</code> is instead initialized to <em><code>117440</code></em>.</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_year_day() {
+        assert_eq!(Some((2024, 17)), PuzzlePage::new(PAGE).year_day());
+        assert_eq!(
+            None,
+            PuzzlePage::new("<title>Advent of Code</title>").year_day()
+        );
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            vec![
+                "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n",
+                "a <b>\n",
+            ],
+            PuzzlePage::new(PAGE).examples()
+        );
+    }
+
+    #[test]
+    fn test_answer_candidates() {
+        let candidates = PuzzlePage::new(PAGE).answer_candidates();
+        assert_eq!(
+            vec![
+                (
+                    Part::One,
+                    vec!["4,6,3,5,6,3,5,2,1,0".to_owned(), "1".to_owned()]
+                ),
+                (Part::Two, vec!["117440".to_owned()]),
+            ],
+            candidates
+        );
+    }
+}
@@ -0,0 +1,505 @@
+//! Creates new days, and the year crates to hold them, from templates.
+
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+const PLAIN: &str = include_str!("../templates/plain.rs.tmpl");
+const GRID: &str = include_str!("../templates/grid.rs.tmpl");
+const GRAPH: &str = include_str!("../templates/graph.rs.tmpl");
+
+/// The starting point for a new day's `main.rs`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Template {
+    /// Parts take the raw puzzle text
+    #[default]
+    Plain,
+    /// The puzzle text is parsed into a `Grid<char>`
+    Grid,
+    /// The puzzle text is parsed into a `Grid<usize>` and searched with `astar`
+    Graph,
+}
+
+impl Template {
+    fn render(&self, year: u16, day: u8) -> String {
+        let template = match self {
+            Template::Plain => PLAIN,
+            Template::Grid => GRID,
+            Template::Graph => GRAPH,
+        };
+        template
+            .replace("{{YEAR}}", &year.to_string())
+            .replace("{{DAY}}", &day.to_string())
+    }
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Template::Plain),
+            "grid" => Ok(Template::Grid),
+            "graph" => Ok(Template::Graph),
+            other => Err(format!(
+                "bad template '{other}', expected grid, graph or plain"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Template::Plain => "plain",
+            Template::Grid => "grid",
+            Template::Graph => "graph",
+        })
+    }
+}
+
+/// A workspace laid out like this one: a crate per year beside the `aoc` runner.
+pub struct Workspace {
+    root: PathBuf,
+}
+
+impl Workspace {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The years with a crate, oldest first
+    pub fn years(&self) -> Vec<u16> {
+        let mut years = numbered_dirs(&self.root, "");
+        years.sort();
+        years
+    }
+
+    /// The days created so far for `year`, in order
+    pub fn days(&self, year: u16) -> Vec<u8> {
+        let mut days = numbered_dirs(&self.year_dir(year), "d");
+        days.sort();
+        days
+    }
+
+    /// The day after the last one created for `year`
+    pub fn next_day(&self, year: u16) -> u8 {
+        self.days(year).last().map_or(1, |d| d + 1)
+    }
+
+    fn year_dir(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string())
+    }
+
+    /// Writes `year`'s `d<day>/main.rs` from `template`, creating the year
+    /// crate if needed, and registers the day with its crate and the `aoc` runner.
+    ///
+    /// Every edit is worked out before anything is written, so a day that can't be
+    /// registered leaves the workspace untouched. Returns the files created. An
+    /// existing day is never overwritten.
+    pub fn create_day(
+        &self,
+        year: u16,
+        day: u8,
+        template: Template,
+    ) -> Result<Vec<PathBuf>, String> {
+        if !(1..=25).contains(&day) {
+            return Err(format!("bad day {day}, expected 1 to 25"));
+        }
+
+        let year_dir = self.year_dir(year);
+        let day_dir = year_dir.join(format!("d{day}"));
+        let main_rs = day_dir.join("main.rs");
+        if main_rs.exists() {
+            return Err(format!(
+                "{year} day {day} already exists at {}",
+                main_rs.display()
+            ));
+        }
+
+        let cargo_toml = year_dir.join("Cargo.toml");
+        let lib_rs = year_dir.join("lib.rs");
+        let new_year = !cargo_toml.exists();
+        let (mut edits, year_toml, year_lib) = if new_year {
+            (self.register_year(year)?, year_toml(year), year_lib(year))
+        } else if !lib_rs.exists() {
+            return Err(format!(
+                "{year} has no lib.rs to register day {day} with, it isn't run by `aoc` yet"
+            ));
+        } else {
+            (vec![], read(&cargo_toml)?, read(&lib_rs)?)
+        };
+        edits.push((
+            cargo_toml.clone(),
+            format!(
+                "{}\n\n[[bin]]\nname = \"d{day}\"\npath = \"d{day}/main.rs\"\n",
+                year_toml.trim_end()
+            ),
+        ));
+        edits.push((lib_rs.clone(), register_day(&year_lib, day)?));
+
+        let mut created = vec![];
+        if new_year {
+            created.extend([cargo_toml, lib_rs]);
+        }
+        fs::create_dir_all(&day_dir).map_err(|e| write_error(&day_dir, e))?;
+        write(&main_rs, &template.render(year, day))?;
+        created.push(main_rs);
+
+        let test_input = day_dir.join("input.test.txt");
+        if !test_input.exists() {
+            write(&test_input, "")?;
+            created.push(test_input);
+        }
+
+        for (path, contents) in edits {
+            write(&path, &contents)?;
+        }
+        Ok(created)
+    }
+
+    /// The edits adding a new year to the workspace, the `aoc` runner's
+    /// dependencies and its `solutions`
+    fn register_year(&self, year: u16) -> Result<Vec<(PathBuf, String)>, String> {
+        let workspace_toml = self.root.join("Cargo.toml");
+        let member = |l: &str| {
+            l.trim()
+                .trim_end_matches(',')
+                .trim_matches('"')
+                .parse()
+                .ok()
+        };
+        let toml = read(&workspace_toml)?;
+        let at = sorted_position(&toml, year, member).ok_or("no workspace members")?;
+        let workspace_toml_edit = insert_lines(&toml, at, &format!("    \"{year}\","));
+
+        let aoc_toml = self.root.join("aoc").join("Cargo.toml");
+        let dependency = |l: &str| l.strip_prefix("aoc")?.split_once(' ')?.0.parse().ok();
+        let toml = read(&aoc_toml)?;
+        let at = sorted_position(&toml, year, dependency).ok_or("no year dependencies")?;
+        let aoc_toml_edit =
+            insert_lines(&toml, at, &format!("aoc{year} = {{path = \"../{year}\"}}"));
+
+        let aoc_lib = self.root.join("aoc").join("src").join("lib.rs");
+        let solutions = |l: &str| {
+            l.trim()
+                .strip_prefix("aoc")?
+                .strip_suffix("::SOLUTIONS,")?
+                .parse()
+                .ok()
+        };
+        let lib = read(&aoc_lib)?;
+        let at = sorted_position(&lib, year, solutions).ok_or("no year solutions")?;
+        let aoc_lib_edit = insert_lines(&lib, at, &format!("        aoc{year}::SOLUTIONS,"));
+
+        Ok(vec![
+            (workspace_toml, workspace_toml_edit),
+            (aoc_toml, aoc_toml_edit),
+            (aoc_lib, aoc_lib_edit),
+        ])
+    }
+}
+
+fn year_toml(year: u16) -> String {
+    format!(
+        "\
+[package]
+name = \"aoc{year}\"
+version = \"0.1.0\"
+edition = \"2024\"
+
+[dependencies]
+itertools = \"0.10.5\"
+aoclib = {{path = \"../lib\"}}
+
+[lib]
+path = \"lib.rs\"
+test = false
+doctest = false
+"
+    )
+}
+
+fn year_lib(year: u16) -> String {
+    format!(
+        "\
+//! Every {year} day as a module so the `aoc` runner can solve them in-process.
+//! Each day's `main` is only used by its own binary.
+#![allow(dead_code)]
+
+use aoclib::solution::Runnable;
+
+pub static SOLUTIONS: &[&dyn Runnable] = &[
+];
+"
+    )
+}
+
+/// Adds `day`'s module and its entry in `SOLUTIONS` to a year's `lib.rs`, keeping both in order
+fn register_day(lib: &str, day: u8) -> Result<String, String> {
+    let missing = || "no SOLUTIONS to register the day with".to_owned();
+
+    let module = |l: &str| numbered(l, "#[path = \"d");
+    let at = lib
+        .lines()
+        .position(|l| module(l).is_some_and(|d: u8| d > day))
+        .or_else(|| line_starting(lib, "pub static SOLUTIONS"))
+        .ok_or_else(missing)?;
+    let lib = insert_lines(
+        lib,
+        at,
+        &format!("#[path = \"d{day}/main.rs\"]\npub mod d{day};\n"),
+    );
+
+    let solution = |l: &str| numbered(l.trim(), "&d");
+    let at = sorted_position(&lib, day, solution)
+        .or_else(|| line_starting(&lib, "];"))
+        .ok_or_else(missing)?;
+    Ok(insert_lines(&lib, at, &format!("    &d{day}::Day{day},")))
+}
+
+/// The number straight after `prefix`, eg: 17 from `&d17::Day17`
+fn numbered<N: FromStr>(line: &str, prefix: &str) -> Option<N> {
+    let rest = line.strip_prefix(prefix)?;
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+/// The line to insert something numbered `n` at so the lines `number`
+/// recognises stay in order: before the first numbered higher, else after the last.
+fn sorted_position<N: Ord>(txt: &str, n: N, number: impl Fn(&str) -> Option<N>) -> Option<usize> {
+    let numbered: Vec<(usize, N)> = txt
+        .lines()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, number(l)?)))
+        .collect();
+    numbered
+        .iter()
+        .find(|(_, found)| *found > n)
+        .map(|(i, _)| *i)
+        .or_else(|| numbered.last().map(|(i, _)| i + 1))
+}
+
+fn line_starting(txt: &str, prefix: &str) -> Option<usize> {
+    txt.lines().position(|l| l.starts_with(prefix))
+}
+
+/// Inserts `entry`, which may span several lines, before line `at`
+fn insert_lines(txt: &str, at: usize, entry: &str) -> String {
+    let mut lines: Vec<&str> = txt.lines().collect();
+    lines.splice(at..at, entry.split('\n'));
+    lines.join("\n") + "\n"
+}
+
+fn numbered_dirs<N: FromStr>(dir: &Path, prefix: &str) -> Vec<N> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| e.file_name().to_str()?.strip_prefix(prefix)?.parse().ok())
+        .collect()
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| write_error(path, e))
+}
+
+fn write_error(path: &Path, e: std::io::Error) -> String {
+    format!("can't write {}: {e}", path.display())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("can't read {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        process::{self, Command},
+    };
+
+    use super::*;
+
+    const YEAR_LIB: &str = "\
+use aoclib::solution::Runnable;
+
+#[path = \"d1/main.rs\"]
+pub mod d1;
+
+#[path = \"d3/main.rs\"]
+pub mod d3;
+
+pub static SOLUTIONS: &[&dyn Runnable] = &[
+    &d1::Day1,
+    &d3::Day3,
+];
+";
+
+    /// A workspace with just enough of the real one to add years to
+    fn workspace(name: &str) -> (PathBuf, Workspace) {
+        let root = env::temp_dir().join(format!("aoc-new-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::create_dir_all(root.join("2024")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"2024\",\n    \"aoc\",\n    \"lib\"\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\naoclib = {path = \"../lib\"}\naoc2024 = {path = \"../2024\"}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("lib.rs"),
+            "    [\n        aoc2024::SOLUTIONS,\n    ]\n",
+        )
+        .unwrap();
+        fs::write(root.join("2024").join("Cargo.toml"), "[package]\n").unwrap();
+        fs::write(root.join("2024").join("lib.rs"), YEAR_LIB).unwrap();
+        fs::create_dir_all(root.join("2024").join("d1")).unwrap();
+        fs::create_dir_all(root.join("2024").join("d3")).unwrap();
+        fs::write(root.join("2024").join("d3").join("main.rs"), "").unwrap();
+
+        let workspace = Workspace::new(&root);
+        (root, workspace)
+    }
+
+    #[test]
+    fn test_register_day_in_order() {
+        let lib = register_day(YEAR_LIB, 2).unwrap();
+        assert!(lib.contains("pub mod d1;\n\n#[path = \"d2/main.rs\"]\npub mod d2;\n\n#[path"));
+        assert!(lib.contains("    &d1::Day1,\n    &d2::Day2,\n    &d3::Day3,\n"));
+
+        let lib = register_day(&lib, 10).unwrap();
+        assert!(lib.contains("pub mod d10;\n\npub static SOLUTIONS"));
+        assert!(lib.contains("    &d3::Day3,\n    &d10::Day10,\n];"));
+    }
+
+    #[test]
+    fn test_next_day() {
+        let (root, workspace) = workspace("next-day");
+        assert_eq!(vec![2024], workspace.years());
+        assert_eq!(4, workspace.next_day(2024));
+        assert_eq!(1, workspace.next_day(2030));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_create_day_refuses_to_overwrite() {
+        let (root, workspace) = workspace("overwrite");
+        assert!(workspace.create_day(2024, 3, Template::Plain).is_err());
+        assert_eq!(
+            "",
+            fs::read_to_string(root.join("2024/d3/main.rs")).unwrap()
+        );
+        assert!(workspace.create_day(2024, 26, Template::Plain).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_create_day_without_lib_writes_nothing() {
+        let (root, workspace) = workspace("no-lib");
+        fs::remove_file(root.join("2024/lib.rs")).unwrap();
+
+        let err = workspace.create_day(2024, 4, Template::Plain).unwrap_err();
+        assert!(err.contains("no lib.rs"), "{err}");
+        assert!(!root.join("2024/d4").exists());
+        assert_eq!(
+            "[package]\n",
+            fs::read_to_string(root.join("2024/Cargo.toml")).unwrap()
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_create_year() {
+        let (root, workspace) = workspace("year");
+        workspace.create_day(2023, 5, Template::Grid).unwrap();
+
+        let main_rs = fs::read_to_string(root.join("2023/d5/main.rs")).unwrap();
+        assert!(main_rs.contains("pub struct Day5;"));
+        assert!(main_rs.contains("const YEAR: u16 = 2023;"));
+        assert!(
+            fs::read_to_string(root.join("2023/Cargo.toml"))
+                .unwrap()
+                .ends_with("[[bin]]\nname = \"d5\"\npath = \"d5/main.rs\"\n")
+        );
+        assert!(
+            fs::read_to_string(root.join("2023/lib.rs"))
+                .unwrap()
+                .contains(
+                    "pub mod d5;\n\npub static SOLUTIONS: &[&dyn Runnable] = &[\n    &d5::Day5,\n];"
+                )
+        );
+        assert!(
+            fs::read_to_string(root.join("Cargo.toml"))
+                .unwrap()
+                .contains("    \"2023\",\n    \"2024\",")
+        );
+        assert!(
+            fs::read_to_string(root.join("aoc/Cargo.toml"))
+                .unwrap()
+                .contains("aoc2023 = {path = \"../2023\"}\naoc2024")
+        );
+        assert!(
+            fs::read_to_string(root.join("aoc/src/lib.rs"))
+                .unwrap()
+                .contains("aoc2023::SOLUTIONS,\n        aoc2024::SOLUTIONS,")
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    /// Every template has to build against the real `aoclib`, with its tests
+    #[test]
+    fn test_templates_compile() {
+        let root = env::temp_dir().join(format!("aoc-new-compile-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("2024")).unwrap();
+        let real = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nresolver = \"2\"\nmembers = [\"2024\"]\n",
+        )
+        .unwrap();
+        // the same versions as the real workspace, when it has a lock file yet
+        let _ = fs::copy(real.join("Cargo.lock"), root.join("Cargo.lock"));
+        let lib = real.join("lib").display().to_string().replace('\\', "/");
+        fs::write(
+            root.join("2024/Cargo.toml"),
+            year_toml(2024).replace("../lib", &lib),
+        )
+        .unwrap();
+        fs::write(root.join("2024/lib.rs"), year_lib(2024)).unwrap();
+
+        let workspace = Workspace::new(&root);
+        for (day, template) in [
+            (1, Template::Plain),
+            (2, Template::Grid),
+            (3, Template::Graph),
+        ] {
+            workspace.create_day(2024, day, template).unwrap();
+        }
+
+        // its own target dir, as the one running this test is locked
+        let target = env::var_os("CARGO_TARGET_DIR")
+            .map_or_else(|| real.join("target"), PathBuf::from)
+            .join("scaffold-check");
+        let status = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()))
+            .args(["check", "--tests", "--offline", "--quiet"])
+            .current_dir(&root)
+            .env("CARGO_TARGET_DIR", target)
+            .status()
+            .unwrap();
+        assert!(status.success(), "scaffolded days don't build");
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,308 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::{
+    Answer, input,
+    solution::{self, Part, Solution},
+};
+
+/// The accepted answers for a year's puzzle inputs, kept in `<year>/answers.toml`
+/// so tests and binaries can check results without hard-coding them.
+///
+/// Only the subset of TOML needed is understood: a table per day
+/// holding `part1` and `part2` as integers or strings.
+///
+/// ```
+/// use aoclib::{answers::{Answers, Verdict}, solution::Part};
+///
+/// let answers: Answers = "\
+/// [d17]
+/// part1 = \"3,6,7,0,5,7,3,1,4\"
+/// part2 = 164278496489149
+/// "
+/// .parse()
+/// .unwrap();
+///
+/// assert_eq!(Verdict::Correct, answers.check(17, Part::One, "3,6,7,0,5,7,3,1,4"));
+/// assert_eq!(Verdict::Unknown, answers.check(18, Part::One, "22"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// No answer has been accepted for this part yet
+    Unknown,
+}
+
+impl Verdict {
+    /// A single word for the verdict, leaving out the expected answer
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::Wrong { expected } if expected.contains('\n') => {
+                write!(f, "wrong, expected:\n{expected}")
+            }
+            Verdict::Wrong { expected } => write!(f, "wrong, expected {expected}"),
+            Verdict::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, e) => write!(f, "can't access {}: {e}", path.display()),
+            AnswersError::Parse(path, e) => write!(f, "bad answers in {}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl Answers {
+    /// Where a year keeps its answers, eg: `2024/answers.toml`
+    pub fn path(year: u16) -> PathBuf {
+        input::workspace_root()
+            .join(year.to_string())
+            .join("answers.toml")
+    }
+
+    /// The answers accepted so far for a year, empty if none have been recorded
+    pub fn load(year: u16) -> Result<Self, AnswersError> {
+        let path = Self::path(year);
+        match fs::read_to_string(&path) {
+            Ok(txt) => txt.parse().map_err(|e| AnswersError::Parse(path, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AnswersError::Io(path, e)),
+        }
+    }
+
+    pub fn save(&self, year: u16) -> Result<(), AnswersError> {
+        let path = Self::path(year);
+        fs::write(&path, self.to_string()).map_err(|e| AnswersError::Io(path, e))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|a| a.as_str())
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: impl fmt::Display) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Accepts `answer`, returning the answer it replaced if there was one
+    pub fn record(&mut self, day: u8, part: Part, answer: impl Into<String>) -> Option<String> {
+        self.answers.insert((day, part), answer.into())
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            let err = |msg: &str| format!("line {}: {msg} in '{line}'", i + 1);
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let d = table
+                    .strip_prefix('d')
+                    .and_then(|d| d.parse::<u8>().ok())
+                    .filter(|d| (1..=25).contains(d))
+                    .ok_or_else(|| err("expected a day table such as [d17]"))?;
+                day = Some(d);
+                continue;
+            }
+
+            let (part, value) = parse_answer(line).map_err(err)?;
+            let day = day.ok_or_else(|| err("answer before any day table"))?;
+
+            answers.record(day, part, value);
+        }
+
+        Ok(answers)
+    }
+}
+
+/// A `part1 = <answer>` line
+pub(crate) fn parse_answer(line: &str) -> Result<(Part, String), &'static str> {
+    let (key, value) = line.split_once('=').ok_or("expected part1 = <answer>")?;
+    let part = match key.trim() {
+        "part1" => Part::One,
+        "part2" => Part::Two,
+        _ => return Err("expected part1 or part2"),
+    };
+    let value: Answer = value.trim().parse()?;
+    Ok((part, value.to_string()))
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Accepted answers for this year's puzzle inputs.")?;
+        writeln!(
+            f,
+            "# Record new ones with `aoc accept <year> <day> <part>`."
+        )?;
+
+        let mut day = None;
+        for ((d, part), answer) in &self.answers {
+            if day != Some(*d) {
+                writeln!(f)?;
+                writeln!(f, "[d{d}]")?;
+                day = Some(*d);
+            }
+            write_answer(f, *part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the `part1 = <answer>` line [`parse_answer`] reads back
+pub(crate) fn write_answer(f: &mut fmt::Formatter<'_>, part: Part, answer: &str) -> fmt::Result {
+    // only digits that print back the same can go bare, anything else
+    // would come back as a different answer
+    let value = match answer.parse::<i128>() {
+        Ok(n) if n.to_string() == answer => Answer::Integer(n),
+        _ => Answer::from(answer),
+    };
+    writeln!(f, "{part} = {}", value.to_toml())
+}
+
+/// For tests: solves `part` against the real puzzle input and asserts it
+/// matches the accepted answer.
+///
+/// Returns early, after saying why, when the input hasn't been downloaded
+/// or no answer has been accepted yet.
+///
+/// ```ignore
+/// #[test]
+/// fn input_pt1() {
+///     aoclib::answers::assert_correct::<Day17>(Part::One);
+/// }
+/// ```
+pub fn assert_correct<S: Solution>(part: Part) {
+    let Some(txt) = input::load_or_skip(S::YEAR, S::DAY) else {
+        return;
+    };
+    let answers = Answers::load(S::YEAR).unwrap_or_else(|e| panic!("{e}"));
+    let Some(expected) = answers.get(S::DAY, part) else {
+        // written directly so the test harness doesn't capture it
+        let _ = writeln!(
+            io::stderr(),
+            "skipping {} day {} {part}: no accepted answer",
+            S::YEAR,
+            S::DAY
+        );
+        return;
+    };
+
+    let result = solution::run::<S>(&txt, &[part]);
+    assert_eq!(expected, result.parts[0].answer.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+# comment
+
+[d1]
+part1 = 1580061
+part2 = -23
+
+[d17]
+part1 = \"3,6,7,0,5,7,3,1,4\"
+";
+
+    #[test]
+    fn test_parse() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+
+        assert_eq!(Some("1580061"), answers.get(1, Part::One));
+        assert_eq!(Some("-23"), answers.get(1, Part::Two));
+        assert_eq!(Some("3,6,7,0,5,7,3,1,4"), answers.get(17, Part::One));
+        assert_eq!(None, answers.get(17, Part::Two));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!("part1 = 1".parse::<Answers>().is_err());
+        assert!("[d26]".parse::<Answers>().is_err());
+        assert!("[d1]\npart3 = 1".parse::<Answers>().is_err());
+        assert!("[d1]\npart1 = abc".parse::<Answers>().is_err());
+
+        let err = "[d1]\npart1 = 1\npart2 = \"unterminated"
+            .parse::<Answers>()
+            .unwrap_err();
+        assert!(err.starts_with("line 3"), "{err}");
+    }
+
+    #[test]
+    fn test_check() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+
+        assert_eq!(Verdict::Correct, answers.check(1, Part::One, "1580061"));
+        assert_eq!(
+            Verdict::Wrong {
+                expected: "1580061".to_owned()
+            },
+            answers.check(1, Part::One, "1580062")
+        );
+        assert_eq!(Verdict::Unknown, answers.check(2, Part::One, "1"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers: Answers = ANSWERS.parse().unwrap();
+        assert_eq!(None, answers.record(2, Part::One, "a \"quoted\"\nanswer"));
+        assert_eq!(Some("-23".to_owned()), answers.record(1, Part::Two, "24"));
+        assert_eq!(None, answers.record(3, Part::One, "0123"));
+
+        let reparsed: Answers = answers.to_string().parse().unwrap();
+        assert_eq!(answers, reparsed);
+        assert_eq!(Some("a \"quoted\"\nanswer"), reparsed.get(2, Part::One));
+        assert!(answers.to_string().contains("part1 = \"0123\"\n"));
+    }
+}
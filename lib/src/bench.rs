@@ -0,0 +1,321 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input,
+    solution::{Part, Runnable},
+    timing::format_elapsed_time,
+};
+
+/// Stages faster than this are too noisy to call a regression however
+/// much slower they got.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+/// How long to spend benchmarking each day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub iterations: usize,
+    /// Stop early once this has been spent, so slow days still finish.
+    /// At least one iteration always runs.
+    pub max_time: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            iterations: 100,
+            max_time: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Summary of the time taken over many iterations of one stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", with = "nanos")]
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// `None` without any samples
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let percentile = |p: usize| sorted[(sorted.len() * p).div_ceil(100).max(1) - 1];
+
+        Some(Self {
+            min: *sorted.first()?,
+            median: percentile(50),
+            p95: percentile(95),
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>7}  median {:>7}  p95 {:>7}",
+            format_elapsed_time(self.min),
+            format_elapsed_time(self.median),
+            format_elapsed_time(self.p95)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => part.fmt(f),
+        }
+    }
+}
+
+/// The timings for one day, with parsing and each part measured separately
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    pub fn stages(&self) -> [(Stage, &Stats); 3] {
+        [
+            (Stage::Parse, &self.parse),
+            (Stage::Part(Part::One), &self.part1),
+            (Stage::Part(Part::Two), &self.part2),
+        ]
+    }
+
+    /// The stages whose median is more than `threshold` (eg: 0.1 for 10%)
+    /// slower than in `baseline`
+    pub fn regressions(&self, baseline: &DayBench, threshold: f64) -> Vec<Regression> {
+        self.stages()
+            .into_iter()
+            .zip(baseline.stages())
+            .filter_map(|((stage, current), (_, before))| {
+                let regression = Regression {
+                    year: self.year,
+                    day: self.day,
+                    stage,
+                    baseline: before.median,
+                    current: current.median,
+                };
+                let slower = current.median.saturating_sub(before.median);
+                (slower > NOISE_FLOOR && regression.ratio() > 1.0 + threshold).then_some(regression)
+            })
+            .collect()
+    }
+}
+
+/// Runs `solution` against `txt` repeatedly, timing each stage of every run
+pub fn bench(solution: &dyn Runnable, txt: &str, options: &Options) -> DayBench {
+    let start = Instant::now();
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+
+    while parse.is_empty()
+        || (parse.len() < options.iterations && start.elapsed() < options.max_time)
+    {
+        let run = solution.run(txt, &Part::ALL);
+        parse.push(run.parse);
+        for p in run.parts {
+            match p.part {
+                Part::One => part1.push(p.elapsed),
+                Part::Two => part2.push(p.elapsed),
+            }
+        }
+    }
+
+    DayBench {
+        year: solution.year(),
+        day: solution.day(),
+        iterations: parse.len(),
+        parse: Stats::from_samples(&parse).expect("at least one iteration"),
+        part1: Stats::from_samples(&part1).expect("at least one iteration"),
+        part2: Stats::from_samples(&part2).expect("at least one iteration"),
+    }
+}
+
+/// A stage that got slower than its baseline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// How many times slower the stage now is
+    pub fn ratio(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {} {} regressed: median {} -> {} (+{:.0}%)",
+            self.year,
+            self.day,
+            self.stage,
+            format_elapsed_time(self.baseline),
+            format_elapsed_time(self.current),
+            (self.ratio() - 1.0) * 100.0
+        )
+    }
+}
+
+/// Saved benchmark results to compare later runs against
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: Vec<DayBench>,
+}
+
+impl Baseline {
+    /// Where the baseline is kept unless told otherwise.
+    /// Timings only mean something on the machine that took them, so it isn't committed.
+    pub fn default_path() -> PathBuf {
+        input::workspace_root().join("bench.json")
+    }
+
+    /// An empty baseline if none has been saved yet
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(io::Error::other),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&DayBench> {
+        self.days.iter().find(|d| d.year == year && d.day == day)
+    }
+
+    /// Adds `result`, replacing any earlier result for the same day
+    pub fn record(&mut self, result: DayBench) {
+        self.days
+            .retain(|d| (d.year, d.day) != (result.year, result.day));
+        self.days.push(result);
+        self.days.sort_by_key(|d| (d.year, d.day));
+    }
+}
+
+/// (De)serializes a `Duration` as whole nanoseconds
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        u64::deserialize(d).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(us: u64) -> Duration {
+        Duration::from_micros(us)
+    }
+
+    fn stats(median_us: u64) -> Stats {
+        Stats {
+            min: micros(median_us / 2),
+            median: micros(median_us),
+            p95: micros(median_us * 2),
+        }
+    }
+
+    fn day(parse: u64, part1: u64, part2: u64) -> DayBench {
+        DayBench {
+            year: 2024,
+            day: 1,
+            iterations: 10,
+            parse: stats(parse),
+            part1: stats(part1),
+            part2: stats(part2),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<_> = (1..=100).rev().map(micros).collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(micros(1), stats.min);
+        assert_eq!(micros(50), stats.median);
+        assert_eq!(micros(95), stats.p95);
+
+        let single = Stats::from_samples(&[micros(7)]).unwrap();
+        assert_eq!((micros(7), micros(7)), (single.median, single.p95));
+
+        assert_eq!(None, Stats::from_samples(&[]));
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = day(10, 1_000, 1_000);
+
+        // parse doubled but is under the noise floor, part2 is within the threshold
+        let current = day(20, 2_000, 1_050);
+        let regressions = current.regressions(&baseline, 0.1);
+
+        assert_eq!(1, regressions.len());
+        assert_eq!(Stage::Part(Part::One), regressions[0].stage);
+        assert_eq!(2.0, regressions[0].ratio());
+        assert!(current.regressions(&current, 0.1).is_empty());
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.record(day(10, 20, 30));
+        baseline.record(DayBench {
+            day: 2,
+            ..day(1, 2, 3)
+        });
+        baseline.record(day(40, 50, 60));
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert!(json.contains("\"median_ns\":40000"), "{json}");
+        let reparsed: Baseline = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(baseline, reparsed);
+        assert_eq!(2, reparsed.days.len());
+        assert_eq!(Some(&day(40, 50, 60)), reparsed.get(2024, 1));
+    }
+}
@@ -0,0 +1,491 @@
+//! Points, vectors and boxes in three dimensions, for the days set in space
+//! or among falling blocks.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// A position in space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// The difference between two [`Point3`]s
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Point3) -> i64 {
+        (*other - *self).manhattan_length()
+    }
+
+    /// The straight line distance squared, which orders points by distance
+    /// without any floating point
+    pub fn squared_distance(&self, other: &Point3) -> i64 {
+        let v = *other - *self;
+        v.x * v.x + v.y * v.y + v.z * v.z
+    }
+
+    /// The six points sharing a face with this one
+    pub fn neighbours6(&self) -> impl Iterator<Item = Point3> + use<> {
+        let p = *self;
+        Vector3::FACES.into_iter().map(move |v| p + v)
+    }
+
+    /// The 26 points touching this one, by a face, an edge or a corner
+    pub fn neighbours26(&self) -> impl Iterator<Item = Point3> + use<> {
+        let p = *self;
+        Cuboid::new(p - Vector3::new(1, 1, 1), p + Vector3::new(1, 1, 1))
+            .points()
+            .filter(move |n| *n != p)
+    }
+}
+
+impl Vector3 {
+    pub const ZERO: Vector3 = Vector3::new(0, 0, 0);
+
+    /// A step across each face of a cube
+    pub const FACES: [Vector3; 6] = [
+        Vector3::new(1, 0, 0),
+        Vector3::new(-1, 0, 0),
+        Vector3::new(0, 1, 0),
+        Vector3::new(0, -1, 0),
+        Vector3::new(0, 0, 1),
+        Vector3::new(0, 0, -1),
+    ];
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Vector3 { x, y, z }
+    }
+
+    pub fn manhattan_length(&self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    fn to_array(self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl Add<Vector3> for Point3 {
+    type Output = Point3;
+
+    fn add(self, v: Vector3) -> Self::Output {
+        Point3::new(self.x + v.x, self.y + v.y, self.z + v.z)
+    }
+}
+
+impl AddAssign<Vector3> for Point3 {
+    fn add_assign(&mut self, v: Vector3) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector3> for Point3 {
+    type Output = Point3;
+
+    fn sub(self, v: Vector3) -> Self::Output {
+        self + -v
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Vector3;
+
+    fn sub(self, other: Point3) -> Self::Output {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, v: Vector3) -> Self::Output {
+        Vector3::new(self.x + v.x, self.y + v.y, self.z + v.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, v: Vector3) -> Self::Output {
+        self + -v
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Self::Output {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, n: i64) -> Self::Output {
+        Vector3::new(self.x * n, self.y * n, self.z * n)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl From<(i64, i64, i64)> for Vector3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Vector3::new(x, y, z)
+    }
+}
+
+/// Parses the usual `x,y,z` form
+///
+/// ```
+/// use aoclib::cartesian3::Point3;
+///
+/// assert_eq!(Ok(Point3::new(162, -817, 812)), "162,-817,812".parse());
+/// ```
+impl FromStr for Point3 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords: Vec<i64> = s
+            .trim()
+            .split(',')
+            .map(|n| {
+                n.trim()
+                    .parse()
+                    .map_err(|e| format!("bad point '{s}': {e}"))
+            })
+            .collect::<Result<_, _>>()?;
+        match coords[..] {
+            [x, y, z] => Ok(Point3::new(x, y, z)),
+            _ => Err(format!("bad point '{s}', expected x,y,z")),
+        }
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// One of the 24 ways to turn something to face along an axis, keeping it
+/// the right way round, eg: a scanner that doesn't know which way it faces.
+///
+/// Each row of the matrix picks one axis with a sign, so turning is
+/// swapping and negating coordinates.
+///
+/// ```
+/// use aoclib::cartesian3::{Rotation, Vector3};
+///
+/// let v = Vector3::new(1, 2, 3);
+/// assert_eq!(24, Rotation::all().count());
+/// assert!(Rotation::all().all(|r| r.inverse().apply(r.apply(v)) == v));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rotation([[i64; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// All 24 rotations, starting with [`Rotation::IDENTITY`]
+    pub fn all() -> impl Iterator<Item = Rotation> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        PERMUTATIONS
+            .into_iter()
+            .flat_map(|axes| {
+                (0..8).map(move |signs| {
+                    let mut m = [[0; 3]; 3];
+                    for (row, axis) in axes.into_iter().enumerate() {
+                        m[row][axis] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                    }
+                    Rotation(m)
+                })
+            })
+            .filter(|r| r.determinant() == 1)
+    }
+
+    pub fn apply(&self, v: Vector3) -> Vector3 {
+        let [x, y, z] = self
+            .0
+            .map(|row| row.iter().zip(v.to_array()).map(|(a, b)| a * b).sum());
+        Vector3::new(x, y, z)
+    }
+
+    /// Turns `p` about the origin
+    pub fn apply_point(&self, p: Point3) -> Point3 {
+        Point3::ORIGIN + self.apply(p - Point3::ORIGIN)
+    }
+
+    /// This rotation followed by `next`
+    pub fn then(&self, next: &Rotation) -> Rotation {
+        let mut m = [[0; 3]; 3];
+        for (row, cells) in m.iter_mut().enumerate() {
+            for (col, cell) in cells.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| next.0[row][k] * self.0[k][col]).sum();
+            }
+        }
+        Rotation(m)
+    }
+
+    /// The rotation that undoes this one
+    pub fn inverse(&self) -> Rotation {
+        let m = self.0;
+        Rotation(std::array::from_fn(|row| {
+            std::array::from_fn(|col| m[col][row])
+        }))
+    }
+
+    fn determinant(&self) -> i64 {
+        let [a, b, c] = self.0;
+        a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+            + a[2] * (b[0] * c[1] - b[1] * c[0])
+    }
+}
+
+/// A box lined up with the axes, both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cuboid {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Cuboid {
+    /// The box with `a` and `b` as opposite corners, whichever they are
+    pub fn new(a: Point3, b: Point3) -> Self {
+        Cuboid {
+            min: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    /// How many points are inside
+    pub fn volume(&self) -> i64 {
+        let size = self.max - self.min;
+        (size.x + 1) * (size.y + 1) * (size.z + 1)
+    }
+
+    pub fn contains(&self, p: &Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+
+    /// The box both share, `None` when they don't touch
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = Point3::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Point3::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Cuboid { min, max })
+    }
+
+    pub fn translate(&self, v: Vector3) -> Cuboid {
+        Cuboid {
+            min: self.min + v,
+            max: self.max + v,
+        }
+    }
+
+    /// Every point inside, `x` changing fastest then `y` then `z`
+    pub fn points(&self) -> impl Iterator<Item = Point3> + use<> {
+        let Cuboid { min, max } = *self;
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+/// A dense block of cells covering a [`Cuboid`], for voxel puzzles
+///
+/// ```
+/// use aoclib::cartesian3::{Cuboid, Grid3, Point3};
+///
+/// let mut lava = Grid3::new(Cuboid::new(Point3::ORIGIN, Point3::new(2, 2, 2)), false);
+/// lava[Point3::new(1, 1, 1)] = true;
+/// let faces = lava.neighbours6(&Point3::new(1, 1, 0)).filter(|(_, l)| **l).count();
+/// assert_eq!(1, faces);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid3<T> {
+    cells: Vec<T>,
+    bounds: Cuboid,
+}
+
+impl<T: Clone> Grid3<T> {
+    /// A grid covering `bounds` with every cell set to `fill`
+    pub fn new(bounds: Cuboid, fill: T) -> Self {
+        Grid3 {
+            cells: vec![fill; bounds.volume() as usize],
+            bounds,
+        }
+    }
+}
+
+impl<T> Grid3<T> {
+    pub fn bounds(&self) -> &Cuboid {
+        &self.bounds
+    }
+
+    fn index_of(&self, p: &Point3) -> Option<usize> {
+        if !self.bounds.contains(p) {
+            return None;
+        }
+        let size = self.bounds.max - self.bounds.min + Vector3::new(1, 1, 1);
+        let offset = *p - self.bounds.min;
+        Some((offset.x + size.x * (offset.y + size.y * offset.z)) as usize)
+    }
+
+    /// The cell at `p`, `None` when it's outside the grid
+    pub fn get(&self, p: &Point3) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: &Point3) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    pub fn contains(&self, p: &Point3) -> bool {
+        self.bounds.contains(p)
+    }
+
+    /// Every cell with its position, in the order of [`Cuboid::points`]
+    pub fn iter(&self) -> impl Iterator<Item = (Point3, &T)> {
+        self.bounds.points().zip(&self.cells)
+    }
+
+    /// The cells sharing a face with `p`, leaving out any outside the grid
+    pub fn neighbours6(&self, p: &Point3) -> impl Iterator<Item = (Point3, &T)> {
+        p.neighbours6().filter_map(|n| self.get(&n).map(|c| (n, c)))
+    }
+
+    /// The cells touching `p` at all, leaving out any outside the grid
+    pub fn neighbours26(&self, p: &Point3) -> impl Iterator<Item = (Point3, &T)> {
+        p.neighbours26()
+            .filter_map(|n| self.get(&n).map(|c| (n, c)))
+    }
+}
+
+impl<T> Index<Point3> for Grid3<T> {
+    type Output = T;
+
+    fn index(&self, p: Point3) -> &Self::Output {
+        match self.index_of(&p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{p} is outside {:?}", self.bounds),
+        }
+    }
+}
+
+impl<T> IndexMut<Point3> for Grid3<T> {
+    fn index_mut(&mut self, p: Point3) -> &mut Self::Output {
+        match self.index_of(&p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{p} is outside {:?}", self.bounds),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point3::new(1, -1, 0);
+        let faces: HashSet<_> = p.neighbours6().collect();
+        assert_eq!(6, faces.len());
+        assert!(faces.iter().all(|n| n.manhattan(&p) == 1));
+
+        let touching: HashSet<_> = p.neighbours26().collect();
+        assert_eq!(26, touching.len());
+        assert!(!touching.contains(&p));
+        assert!(touching.contains(&Point3::new(2, 0, 1)));
+    }
+
+    #[test]
+    fn test_rotations() {
+        let v = Vector3::new(1, 2, 3);
+        let turned: HashSet<_> = Rotation::all().map(|r| r.apply(v)).collect();
+        assert_eq!(24, turned.len());
+        assert!(
+            turned
+                .iter()
+                .all(|t| t.manhattan_length() == v.manhattan_length())
+        );
+
+        let quarter = Rotation([[1, 0, 0], [0, 0, -1], [0, 1, 0]]);
+        assert_eq!(Vector3::new(1, -3, 2), quarter.apply(v));
+        let half = quarter.then(&quarter);
+        assert_eq!(Vector3::new(1, -2, -3), half.apply(v));
+        assert_eq!(Rotation::IDENTITY, half.then(&half));
+        assert_eq!(Some(Rotation::IDENTITY), Rotation::all().next());
+    }
+
+    #[test]
+    fn test_cuboids() {
+        let a = Cuboid::new(Point3::new(12, 12, 12), Point3::new(10, 10, 10));
+        let b = Cuboid::new(Point3::new(11, 11, 11), Point3::new(13, 13, 13));
+        assert_eq!(27, a.volume());
+        assert_eq!(
+            Some(Cuboid::new(
+                Point3::new(11, 11, 11),
+                Point3::new(12, 12, 12)
+            )),
+            a.intersection(&b)
+        );
+        assert_eq!(None, a.intersection(&b.translate(Vector3::new(0, 0, 2))));
+        assert_eq!(27, a.points().filter(|p| a.contains(p)).count());
+    }
+
+    #[test]
+    fn test_grid3() {
+        let bounds = Cuboid::new(Point3::new(-1, -1, -1), Point3::new(1, 2, 3));
+        let mut g = Grid3::new(bounds, 0);
+        for (i, p) in bounds.points().enumerate() {
+            g[p] = i;
+        }
+        assert_eq!(
+            (0..bounds.volume() as usize).collect::<Vec<_>>(),
+            g.iter().map(|(_, i)| *i).collect::<Vec<_>>()
+        );
+        assert_eq!(None, g.get(&Point3::new(2, 0, 0)));
+        assert_eq!(3, g.neighbours6(&Point3::new(-1, -1, -1)).count());
+        assert_eq!(26, g.neighbours26(&Point3::ORIGIN).count());
+    }
+}
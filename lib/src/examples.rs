@@ -0,0 +1,333 @@
+//! Example inputs kept beside a day's solution, each with the answers the
+//! puzzle gives for it, so adding an example needs a new file rather than
+//! new test code.
+//!
+//! Examples are the `input.test*.txt` files in the day's directory. The
+//! expected answers for `input.test.2.txt` are kept in
+//! `input.test.2.expected.toml`, using the same `part1 = <answer>` lines as
+//! `answers.toml` but without any day tables. A part with no expected answer
+//! isn't checked for that example.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    answers::{parse_answer, write_answer},
+    input,
+    solution::{self, Part, Solution},
+};
+
+/// The answers expected from an example
+///
+/// ```
+/// use aoclib::{examples::Expected, solution::Part};
+///
+/// let expected: Expected = "part1 = 7\npart2 = \"abc\"\n".parse().unwrap();
+/// assert_eq!(Some("7"), expected.get(Part::One));
+/// assert_eq!(Some("abc"), expected.get(Part::Two));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expected {
+    answers: BTreeMap<Part, String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.answers.get(&part).map(|a| a.as_str())
+    }
+
+    pub fn record(&mut self, part: Part, answer: impl Into<String>) -> Option<String> {
+        self.answers.insert(part, answer.into())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl FromStr for Expected {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut expected = Expected::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (part, answer) =
+                parse_answer(line).map_err(|e| format!("line {}: {e} in '{line}'", i + 1))?;
+            expected.record(part, answer);
+        }
+        Ok(expected)
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, answer) in &self.answers {
+            write_answer(f, *part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// An example input and the answers expected from it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub path: PathBuf,
+    pub input: String,
+    pub expected: Expected,
+}
+
+impl Example {
+    /// The file name, eg: `input.test.2.txt`
+    pub fn name(&self) -> String {
+        self.path
+            .file_name()
+            .map_or_else(String::new, |n| n.to_string_lossy().into_owned())
+    }
+}
+
+/// Where the answers expected from an example are kept, eg:
+/// `input.test.2.expected.toml` for `input.test.2.txt`
+pub fn expected_path(example: &Path) -> PathBuf {
+    example.with_extension("expected.toml")
+}
+
+/// The `input.test*.txt` examples in `dir` with their expected answers,
+/// `input.test.txt` first then the rest in number order.
+///
+/// An example without an expected answers file expects nothing.
+pub fn discover(dir: &Path) -> Result<Vec<Example>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("can't read {}: {e}", dir.display()))?;
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("input.test") && n.ends_with(".txt"))
+        })
+        .collect();
+    paths.sort_by_key(|path| {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let number = name
+            .trim_start_matches("input.test")
+            .trim_end_matches(".txt")
+            .trim_start_matches('.');
+        (number.parse::<u32>().ok(), name.into_owned())
+    });
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let input = fs::read_to_string(&path)
+                .map_err(|e| format!("can't read {}: {e}", path.display()))?;
+            let sidecar = expected_path(&path);
+            let expected = match fs::read_to_string(&sidecar) {
+                Ok(txt) => txt
+                    .parse()
+                    .map_err(|e| format!("bad expected answers in {}: {e}", sidecar.display()))?,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Expected::default(),
+                Err(e) => return Err(format!("can't read {}: {e}", sidecar.display())),
+            };
+            Ok(Example {
+                path,
+                input,
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// For tests: solves every example in the day's directory and checks each
+/// part that has an expected answer.
+///
+/// Each example and part is checked on its own, so one failing doesn't hide
+/// the rest: every failure is listed by file and part, with the message of
+/// any panic, then the test fails if any did.
+///
+/// ```ignore
+/// #[test]
+/// fn examples() {
+///     aoclib::examples::assert_examples::<Day17>();
+/// }
+/// ```
+pub fn assert_examples<S: Solution>() {
+    let examples = discover(&input::day_dir(S::YEAR, S::DAY)).unwrap_or_else(|e| panic!("{e}"));
+    let checked: Vec<Result<(), String>> = examples
+        .iter()
+        .flat_map(|example| Part::ALL.map(|part| check::<S>(example, part)))
+        .flatten()
+        .collect();
+    let failures: Vec<&String> = checked.iter().filter_map(|c| c.as_ref().err()).collect();
+
+    if checked.is_empty() {
+        // written directly so the test harness doesn't capture it
+        let _ = writeln!(
+            io::stderr(),
+            "skipping {} day {} examples: no expected answers",
+            S::YEAR,
+            S::DAY
+        );
+    }
+    assert!(
+        failures.is_empty(),
+        "{} of {} example parts failed:\n{}",
+        failures.len(),
+        checked.len(),
+        failures
+            .iter()
+            .map(|f| f.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+/// Solves `part` of `example`, or `None` if it has no expected answer
+fn check<S: Solution>(example: &Example, part: Part) -> Option<Result<(), String>> {
+    let expected = example.expected.get(part)?;
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        solution::run::<S>(&example.input, &[part])
+    }));
+    let failure = match outcome {
+        Ok(run) if run.parts[0].answer == expected => return Some(Ok(())),
+        Ok(run) => format!("expected {expected}, got {}", run.parts[0].answer),
+        Err(payload) => format!("panicked: {}", solution::panic_message(payload.as_ref())),
+    };
+    Some(Err(format!("{} {part}: {failure}", example.name())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 1;
+
+        type Input<'a> = &'a str;
+
+        fn parse(txt: &str) -> Self::Input<'_> {
+            txt
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+            input.lines().count()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+            input.lines().next().expect("an empty example").to_owned()
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("aoclib-examples-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_expected_round_trip() {
+        let mut expected: Expected = "# comment\npart2 = \"a\\nb\"\n".parse().unwrap();
+        assert_eq!(None, expected.get(Part::One));
+        expected.record(Part::One, "12");
+
+        let reparsed: Expected = expected.to_string().parse().unwrap();
+        assert_eq!(expected, reparsed);
+        assert_eq!(Some("a\nb"), reparsed.get(Part::Two));
+
+        let err = "part1 = 1\n[d1]".parse::<Expected>().unwrap_err();
+        assert!(err.starts_with("line 2"), "{err}");
+    }
+
+    #[test]
+    fn test_discover() {
+        let dir = temp_dir("discover");
+        for (name, txt) in [
+            ("input.test.10.txt", "ten"),
+            ("input.test.2.txt", "two"),
+            ("input.test.txt", "one"),
+            ("input.txt", "real"),
+            ("input.test.2.expected.toml", "part1 = 1"),
+        ] {
+            fs::write(dir.join(name), txt).unwrap();
+        }
+
+        let examples = discover(&dir).unwrap();
+        let names: Vec<_> = examples.iter().map(Example::name).collect();
+        assert_eq!(
+            vec!["input.test.txt", "input.test.2.txt", "input.test.10.txt"],
+            names
+        );
+        assert!(examples[0].expected.is_empty());
+        assert_eq!(Some("1"), examples[1].expected.get(Part::One));
+        assert_eq!("ten", examples[2].input);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_discover_bad_expected() {
+        let dir = temp_dir("bad-expected");
+        fs::write(dir.join("input.test.txt"), "").unwrap();
+        fs::write(dir.join("input.test.expected.toml"), "part3 = 1").unwrap();
+
+        let err = discover(&dir).unwrap_err();
+        assert!(err.contains("input.test.expected.toml"), "{err}");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = temp_dir("check");
+        fs::write(dir.join("input.test.txt"), "a\nb\n").unwrap();
+        fs::write(
+            dir.join("input.test.expected.toml"),
+            "part1 = 2\npart2 = \"a\"",
+        )
+        .unwrap();
+        fs::write(dir.join("input.test.2.txt"), "c\n").unwrap();
+        fs::write(dir.join("input.test.2.expected.toml"), "part2 = \"c\"").unwrap();
+        fs::write(dir.join("input.test.3.txt"), "d\n").unwrap();
+
+        let examples = discover(&dir).unwrap();
+        let checked: Vec<_> = examples
+            .iter()
+            .flat_map(|example| Part::ALL.map(|part| check::<Lines>(example, part)))
+            .collect();
+        assert_eq!(
+            vec![Some(Ok(())), Some(Ok(())), None, Some(Ok(())), None, None],
+            checked
+        );
+
+        fs::write(dir.join("input.test.3.expected.toml"), "part1 = 2").unwrap();
+        let examples = discover(&dir).unwrap();
+        assert_eq!(
+            Some(Err("input.test.3.txt part1: expected 2, got 1".to_owned())),
+            check::<Lines>(&examples[2], Part::One)
+        );
+
+        fs::write(dir.join("input.test.3.txt"), "").unwrap();
+        fs::write(dir.join("input.test.3.expected.toml"), "part2 = \"d\"").unwrap();
+        let examples = discover(&dir).unwrap();
+        assert_eq!(
+            Some(Err(
+                "input.test.3.txt part2: panicked: an empty example".to_owned()
+            )),
+            check::<Lines>(&examples[2], Part::Two)
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,8 +1,18 @@
 use core::fmt;
-use std::str::FromStr;
+use std::ops::{Index, IndexMut};
 
 use crate::{cartesian::Plane, input};
 
+mod parse;
+mod search;
+mod sparse;
+mod wrapping;
+
+pub use parse::{GridParseError, GridParseProblem, Markers};
+pub use search::{Orientation, Orientations};
+pub use sparse::SparseGrid;
+pub use wrapping::Wrapping;
+
 /// Utility for a grid of input. A common input type for aoc puzzles.
 /// Supports a number of common utilities on grids of input.
 ///
@@ -28,28 +38,319 @@ use crate::{cartesian::Plane, input};
 ///     println!("{}", grid);
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Cells are kept row by row in a single `Vec`, so looking one up is a
+/// multiply and an add rather than following a pointer per row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
-    pub rows: Vec<Vec<T>>,
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
 }
 
 impl<T> Grid<T> {
+    /// Builds a `Grid` from its rows, which must all be the same length
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridPosition};
+    ///
+    /// let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// assert_eq!(6, g[GridPosition::new(2, 1)]);
+    /// assert_eq!(vec![&3, &6], g.col(2).collect::<Vec<_>>());
+    /// ```
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        for (i, row) in rows.iter().enumerate() {
+            assert!(
+                row.len() == width,
+                "ragged grid: row {i} has {} cells but row 0 has {width}",
+                row.len()
+            );
+        }
+
+        Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
     /// The number of columns within this `Grid`
     pub fn width(&self) -> usize {
-        self.rows[0].len()
+        self.width
     }
 
     /// The number of rows within this `Grid`
     pub fn height(&self) -> usize {
-        self.rows.len()
+        self.height
+    }
+
+    fn index_of(&self, pos: &GridPosition) -> Option<usize> {
+        (pos.col < self.width && pos.row < self.height).then(|| pos.row * self.width + pos.col)
+    }
+
+    /// The cell at `pos`, `None` when it's outside the grid
+    pub fn get(&self, pos: &GridPosition) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: &GridPosition) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
     }
 
     pub fn at(&self, pos: &GridPosition) -> &T {
-        &self.rows[pos.row][pos.col]
+        &self[*pos]
     }
 
     pub fn at_mut(&mut self, pos: &GridPosition) -> &mut T {
-        &mut self.rows[pos.row][pos.col]
+        &mut self[*pos]
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Each row, top to bottom, one per [`Grid::height`] even when they're empty
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// The cells of column `col`, top to bottom
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(
+            col < self.width,
+            "column {col} is outside a grid {} wide",
+            self.width
+        );
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    /// Every cell, row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Whether `pos` lies within the grid
+    pub fn contains(&self, pos: &GridPosition) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    /// The position one step from `pos` in `direction`, `None` when that
+    /// would leave the grid
+    ///
+    /// ```
+    /// use aoclib::grid::{Direction, Grid, GridPosition};
+    ///
+    /// let g: Grid<char> = "ab\ncd".parse().unwrap();
+    /// let corner = GridPosition::new(1, 0);
+    /// assert_eq!(Some(GridPosition::new(1, 1)), g.step(&corner, Direction::Down));
+    /// assert_eq!(None, g.step(&corner, Direction::Right));
+    /// assert_eq!(None, g.step(&corner, Direction::Up));
+    /// ```
+    pub fn step(&self, pos: &GridPosition, direction: Direction) -> Option<GridPosition> {
+        pos.step(direction).filter(|next| self.contains(next))
+    }
+
+    pub fn checked_up(&self, pos: &GridPosition) -> Option<GridPosition> {
+        self.step(pos, Direction::Up)
+    }
+
+    pub fn checked_down(&self, pos: &GridPosition) -> Option<GridPosition> {
+        self.step(pos, Direction::Down)
+    }
+
+    pub fn checked_left(&self, pos: &GridPosition) -> Option<GridPosition> {
+        self.step(pos, Direction::Left)
+    }
+
+    pub fn checked_right(&self, pos: &GridPosition) -> Option<GridPosition> {
+        self.step(pos, Direction::Right)
+    }
+
+    /// The positions sharing an edge with `pos`, with their cells,
+    /// clockwise from up and leaving out any off the edge of the grid
+    pub fn neighbours4(&self, pos: &GridPosition) -> impl Iterator<Item = (GridPosition, &T)> {
+        self.neighbours(pos, &Direction::ORTHOGONAL)
+    }
+
+    /// Like [`Grid::neighbours4`] but including the diagonals
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridPosition};
+    ///
+    /// let g: Grid<char> = "@@.\n.@@".parse().unwrap();
+    /// let paper = g
+    ///     .neighbours8(&GridPosition::new(1, 0))
+    ///     .filter(|(_, c)| **c == '@')
+    ///     .count();
+    /// assert_eq!(3, paper);
+    /// ```
+    pub fn neighbours8(&self, pos: &GridPosition) -> impl Iterator<Item = (GridPosition, &T)> {
+        self.neighbours(pos, &Direction::ALL)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        pos: &GridPosition,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (GridPosition, &'a T)> {
+        let pos = *pos;
+        directions
+            .iter()
+            .filter_map(move |d| self.step(&pos, *d))
+            .map(|p| (p, &self[p]))
+    }
+
+    /// [`Grid::neighbours4`] with mutable cells, in the same order
+    pub fn neighbours4_mut(
+        &mut self,
+        pos: &GridPosition,
+    ) -> impl Iterator<Item = (GridPosition, &mut T)> {
+        self.neighbours_mut(pos, &Direction::ORTHOGONAL)
+    }
+
+    /// [`Grid::neighbours8`] with mutable cells, in the same order
+    pub fn neighbours8_mut(
+        &mut self,
+        pos: &GridPosition,
+    ) -> impl Iterator<Item = (GridPosition, &mut T)> {
+        self.neighbours_mut(pos, &Direction::ALL)
+    }
+
+    fn neighbours_mut(
+        &mut self,
+        pos: &GridPosition,
+        directions: &[Direction],
+    ) -> impl Iterator<Item = (GridPosition, &mut T)> {
+        let positions: Vec<GridPosition> = directions
+            .iter()
+            .filter_map(|d| self.step(pos, *d))
+            .collect();
+        let mut by_row: Vec<usize> = (0..positions.len()).collect();
+        by_row.sort_by_key(|&k| (positions[k].row, positions[k].col));
+
+        // split off each cell in row order so the borrows don't overlap,
+        // then hand them back in direction order
+        let mut rest = self.cells.as_mut_slice();
+        let mut offset = 0;
+        let mut cells: Vec<Option<(GridPosition, &mut T)>> =
+            positions.iter().map(|_| None).collect();
+        for k in by_row {
+            let p = positions[k];
+            let i = p.row * self.width + p.col;
+            let (cell, tail) = std::mem::take(&mut rest)[i - offset..]
+                .split_first_mut()
+                .expect("neighbours are within the grid");
+            cells[k] = Some((p, cell));
+            rest = tail;
+            offset = i + 1;
+        }
+        cells.into_iter().flatten()
+    }
+
+    /// Every position reachable from `start` by steps up, down, left or right
+    /// onto cells matching `predicate`, in the order they're reached.
+    ///
+    /// Empty when `start` itself doesn't match.
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridPosition};
+    ///
+    /// let g: Grid<char> = "..#\n.#.\n#..".parse().unwrap();
+    /// let filled = g.flood_fill(&GridPosition::new(0, 0), |c| *c == '.');
+    /// assert_eq!(3, filled.len());
+    /// ```
+    pub fn flood_fill(
+        &self,
+        start: &GridPosition,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Vec<GridPosition> {
+        if !self.get(start).is_some_and(&mut predicate) {
+            return vec![];
+        }
+        let mut seen = vec![false; self.cells.len()];
+        seen[start.row * self.width + start.col] = true;
+        self.fill(*start, &mut seen, |_, to| predicate(to))
+    }
+
+    /// Splits the grid into regions of touching cells, where two cells next
+    /// to each other are in the same region when `same_region` says so.
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridPosition};
+    ///
+    /// let g: Grid<char> = "AAB\nABB".parse().unwrap();
+    /// let regions = g.components(|a, b| a == b);
+    /// assert_eq!(2, regions.len());
+    ///
+    /// let b = regions.region_at(&GridPosition::new(2, 0));
+    /// assert_eq!((3, 8, 6), (b.area(), b.perimeter(), b.sides()));
+    /// ```
+    pub fn components(&self, mut same_region: impl FnMut(&T, &T) -> bool) -> Regions {
+        let mut seen = vec![false; self.cells.len()];
+        let mut labels = vec![0; self.cells.len()];
+        let mut cells = vec![];
+        for i in 0..self.cells.len() {
+            if seen[i] {
+                continue;
+            }
+            seen[i] = true;
+            let start = GridPosition::new(i % self.width, i / self.width);
+            let region = self.fill(start, &mut seen, &mut same_region);
+            for p in &region {
+                labels[p.row * self.width + p.col] = cells.len();
+            }
+            cells.push(region);
+        }
+
+        let labels = Grid {
+            cells: labels,
+            width: self.width,
+            height: self.height,
+        };
+        let regions = cells
+            .into_iter()
+            .enumerate()
+            .map(|(id, cells)| Region::measure(id, cells, &labels))
+            .collect();
+        Regions { labels, regions }
+    }
+
+    /// Breadth first from `start`, which is already marked as `seen`, onto
+    /// unseen neighbours that `joins` allows stepping to
+    fn fill(
+        &self,
+        start: GridPosition,
+        seen: &mut [bool],
+        mut joins: impl FnMut(&T, &T) -> bool,
+    ) -> Vec<GridPosition> {
+        let mut filled = vec![start];
+        let mut next = 0;
+        while let Some(&pos) = filled.get(next) {
+            next += 1;
+            for (n, cell) in self.neighbours4(&pos) {
+                let i = n.row * self.width + n.col;
+                if !seen[i] && joins(&self[pos], cell) {
+                    seen[i] = true;
+                    filled.push(n);
+                }
+            }
+        }
+        filled
+    }
+
+    /// A grid the same shape with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
     }
 
     pub fn up_from(&self, p: GridPosition) -> impl Iterator<Item = (GridPosition, &T)> {
@@ -103,6 +404,77 @@ impl<T> Grid<T> {
             })
         })
     }
+
+    /// The cells in a line from `from`, not including it, moving `step` at
+    /// a time as `(columns, rows)` until the edge of the grid. A
+    /// [`Direction`] works as a step too.
+    ///
+    /// ```
+    /// use aoclib::grid::{Direction, Grid, GridPosition};
+    ///
+    /// let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    /// let diagonal: Vec<_> = g
+    ///     .ray(&GridPosition::new(0, 0), Direction::DownRight)
+    ///     .map(|(_, n)| *n)
+    ///     .collect();
+    /// assert_eq!(vec![5, 9], diagonal);
+    /// ```
+    pub fn ray(
+        &self,
+        from: &GridPosition,
+        step: impl Into<(isize, isize)>,
+    ) -> impl Iterator<Item = (GridPosition, &T)> {
+        let step = step.into();
+        assert!(step != (0, 0), "a ray has to go somewhere");
+        std::iter::successors(Some(*from), move |p| {
+            p.moved_by(step).filter(|next| self.contains(next))
+        })
+        .skip(1)
+        .map(|p| (p, &self[p]))
+    }
+
+    /// Like [`Grid::ray`] but going off one edge comes back on at the
+    /// opposite one, so it never ends unless the grid is empty
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridPosition};
+    ///
+    /// let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// let slope: Vec<_> = g
+    ///     .ray_wrapping(&GridPosition::new(0, 0), (2, 1))
+    ///     .map(|(_, n)| *n)
+    ///     .take(3)
+    ///     .collect();
+    /// assert_eq!(vec![6, 2, 4], slope);
+    /// ```
+    pub fn ray_wrapping(
+        &self,
+        from: &GridPosition,
+        step: impl Into<(isize, isize)>,
+    ) -> impl Iterator<Item = (GridPosition, &T)> {
+        let (cols, rows) = step.into();
+        assert!((cols, rows) != (0, 0), "a ray has to go somewhere");
+        let (width, height) = (self.width as isize, self.height as isize);
+        let start = (!self.cells.is_empty()).then_some(*from);
+        std::iter::successors(start, move |p| {
+            Some(GridPosition::new(
+                (p.col as isize + cols).rem_euclid(width) as usize,
+                (p.row as isize + rows).rem_euclid(height) as usize,
+            ))
+        })
+        .skip(1)
+        .map(|p| (p, &self[p]))
+    }
+
+    /// The first cell along a [`Grid::ray`] matching `predicate`
+    pub fn first_hit(
+        &self,
+        from: &GridPosition,
+        step: impl Into<(isize, isize)>,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Option<(GridPosition, &T)> {
+        self.ray(from, step).find(|(_, cell)| predicate(cell))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -143,6 +515,293 @@ impl GridPosition {
             col: self.col + 1,
         }
     }
+
+    /// The position one step in `direction`, `None` when that would go
+    /// above the first row or left of the first column.
+    ///
+    /// This knows nothing of the grid's size, use [`Grid::step`] to stay within it.
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        self.moved_by(direction.offset())
+    }
+
+    /// The position `cols` across and `rows` down from this one, `None` when
+    /// that's above the first row or left of the first column
+    pub fn moved_by(&self, (cols, rows): (isize, isize)) -> Option<Self> {
+        Some(Self {
+            col: self.col.checked_add_signed(cols)?,
+            row: self.row.checked_add_signed(rows)?,
+        })
+    }
+}
+
+/// A [`Grid`] split into regions by [`Grid::components`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// The id of the region every cell is in
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    pub fn region_at(&self, pos: &GridPosition) -> &Region {
+        &self.regions[self.labels[*pos]]
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Every region, in order of id
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Region> {
+        self.regions.iter()
+    }
+}
+
+impl Index<usize> for Regions {
+    type Output = Region;
+
+    fn index(&self, id: usize) -> &Self::Output {
+        &self.regions[id]
+    }
+}
+
+/// One region of touching cells from [`Grid::components`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    id: usize,
+    cells: Vec<GridPosition>,
+    perimeter: usize,
+    sides: usize,
+}
+
+impl Region {
+    fn measure(id: usize, cells: Vec<GridPosition>, labels: &Grid<usize>) -> Self {
+        let inside =
+            |p: &GridPosition, d: Direction| labels.step(p, d).is_some_and(|n| labels[n] == id);
+        let mut perimeter = 0;
+        let mut sides = 0;
+        for p in &cells {
+            perimeter += Direction::ORTHOGONAL
+                .iter()
+                .filter(|d| !inside(p, **d))
+                .count();
+            // each diagonal is an outside corner when both edges beside it
+            // are fenced, and an inside corner when neither is but it is
+            for diagonal in Direction::ALL.into_iter().filter(Direction::is_diagonal) {
+                let (a, b) = (inside(p, diagonal.rotate(7)), inside(p, diagonal.rotate(1)));
+                if (!a && !b) || (a && b && !inside(p, diagonal)) {
+                    sides += 1;
+                }
+            }
+        }
+        Region {
+            id,
+            cells,
+            perimeter,
+            sides,
+        }
+    }
+
+    /// The region's label in [`Regions::labels`]
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The positions in the region, the first being the one nearest the
+    /// top left
+    pub fn cells(&self) -> &[GridPosition] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// How many cell edges face out of the region, including those round
+    /// any holes in it
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// How many straight sides the region's outline has, holes included,
+    /// which is also how many corners it has
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
+}
+
+/// A rectangle of positions on a [`Grid`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridRect {
+    pub top_left: GridPosition,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl GridRect {
+    pub fn new(top_left: GridPosition, width: usize, height: usize) -> Self {
+        GridRect {
+            top_left,
+            width,
+            height,
+        }
+    }
+
+    pub fn contains(&self, pos: &GridPosition) -> bool {
+        (self.top_left.col..self.top_left.col + self.width).contains(&pos.col)
+            && (self.top_left.row..self.top_left.row + self.height).contains(&pos.row)
+    }
+}
+
+/// A way to move across a [`Grid`], where up is towards the first row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four directions sharing an edge, clockwise from up
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// All eight directions including the diagonals, clockwise from up
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// How far a step moves as `(columns, rows)`
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        let (cols, rows) = self.offset();
+        cols != 0 && rows != 0
+    }
+
+    /// A quarter turn clockwise
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    /// A quarter turn anticlockwise
+    pub fn turn_left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Turns clockwise by `eighths` of a full turn
+    fn rotate(&self, eighths: usize) -> Self {
+        let i = Direction::ALL
+            .iter()
+            .position(|d| d == self)
+            .expect("every direction");
+        Direction::ALL[(i + eighths) % 8]
+    }
+}
+
+impl<T: PartialOrd> Grid<T> {
+    /// What can be seen from `pos` looking each way in
+    /// [`Direction::ORTHOGONAL`], like trees from a treehouse: each line of
+    /// sight runs to the edge or the first cell at least as high as the one
+    /// at `pos`, including that cell.
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridPosition};
+    ///
+    /// let trees: Grid<u32> = "30373\n25512\n65332\n33549\n35390".parse().unwrap();
+    /// let seen = trees.visible_from(&GridPosition::new(2, 3));
+    /// assert_eq!(vec![2, 2, 1, 2], seen.iter().map(Vec::len).collect::<Vec<_>>());
+    /// ```
+    pub fn visible_from(&self, pos: &GridPosition) -> [Vec<GridPosition>; 4] {
+        let height = &self[*pos];
+        Direction::ORTHOGONAL.map(|d| {
+            let mut seen = vec![];
+            for (p, cell) in self.ray(pos, d) {
+                seen.push(p);
+                if cell >= height {
+                    break;
+                }
+            }
+            seen
+        })
+    }
+}
+
+impl From<Direction> for (isize, isize) {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl<T> Index<GridPosition> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: GridPosition) -> &Self::Output {
+        match self.index_of(&pos) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "{pos:?} is outside a grid {} wide and {} high",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<GridPosition> for Grid<T> {
+    fn index_mut(&mut self, pos: GridPosition) -> &mut Self::Output {
+        match self.index_of(&pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{pos:?} is outside a grid {} wide and {} high",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        Grid::new(rows)
+    }
 }
 
 impl<T> From<&Grid<T>> for Plane {
@@ -157,35 +816,161 @@ where
     T: Clone,
 {
     pub fn transpose(&self) -> Grid<T> {
-        let rows = self.rows.clone();
-        let mut cols = vec![vec![T::default(); rows.len()]; rows[0].len()];
-
-        for col in 0..rows[0].len() {
-            for row in 0..rows.len() {
-                cols[col][row] = rows[row][col].clone();
-            }
+        Grid {
+            cells: (0..self.width)
+                .flat_map(|col| self.col(col).cloned())
+                .collect(),
+            width: self.height,
+            height: self.width,
         }
-
-        Grid { rows: cols }
     }
 }
 
 impl<T: Clone> Grid<T> {
     pub fn rotate_cw(&self) -> Grid<T> {
-        let h = self.height();
-        let w = self.width();
+        assert!(self.height > 0 && self.width > 0);
+
+        Grid {
+            cells: (0..self.width)
+                .flat_map(|col| self.col(col).rev().cloned())
+                .collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        assert!(self.height > 0 && self.width > 0);
+
+        Grid {
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|col| self.col(col).cloned())
+                .collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        Grid {
+            cells: self.cells.iter().rev().cloned().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
 
-        assert!(h > 0 && w > 0);
+    /// Swaps left and right, so each row reads backwards
+    pub fn mirror_h(&self) -> Grid<T> {
+        Grid {
+            cells: self
+                .rows()
+                .flat_map(|row| row.iter().rev())
+                .cloned()
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
 
-        let mut rows = vec![vec![self.rows[0][0].clone(); h]; w];
+    /// Swaps top and bottom, like [`Flip`] but leaving the cells as they are
+    pub fn mirror_v(&self) -> Grid<T> {
+        Grid {
+            cells: self.rows().rev().flatten().cloned().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The grid turned and mirrored every way it can be: the four quarter
+    /// turns clockwise starting from as it is, then the same for its
+    /// [`Grid::mirror_h`]. Symmetric grids repeat themselves.
+    pub fn orientations(&self) -> impl Iterator<Item = Grid<T>> {
+        let turns = |g: Grid<T>| std::iter::successors(Some(g), |g| Some(g.rotate_cw())).take(4);
+        turns(self.clone()).chain(turns(self.mirror_h()))
+    }
+
+    /// The cells within `rect`, `None` when it goes off the edge
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridPosition, GridRect};
+    ///
+    /// let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    /// let middle = GridRect::new(GridPosition::new(1, 1), 2, 2);
+    /// assert_eq!(Some(Grid::new(vec![vec![5, 6], vec![8, 9]])), g.subgrid(&middle));
+    /// ```
+    pub fn subgrid(&self, rect: &GridRect) -> Option<Grid<T>> {
+        let GridRect {
+            top_left,
+            width,
+            height,
+        } = *rect;
+        if top_left.col + width > self.width || top_left.row + height > self.height {
+            return None;
+        }
+        Some(Grid {
+            cells: (top_left.row..top_left.row + height)
+                .flat_map(|row| &self.row(row)[top_left.col..top_left.col + width])
+                .cloned()
+                .collect(),
+            width,
+            height,
+        })
+    }
 
-        for y in 0..h {
-            for x in 0..w {
-                rows[x][h - 1 - y] = self.rows[y][x].clone();
+    /// Copies `other` over this grid with its top left cell at `at`,
+    /// leaving out any of it that falls off the edge
+    pub fn paste(&mut self, at: &GridPosition, other: &Grid<T>) {
+        for (p, cell) in other.position_itr() {
+            let target = GridPosition::new(at.col + p.col, at.row + p.row);
+            if let Some(c) = self.get_mut(&target) {
+                *c = cell.clone();
             }
         }
+    }
 
-        Grid { rows }
+    /// Whether any cell of `other`, placed with its top left at `at`, lands
+    /// on a cell that `predicate` says it clashes with. Cells off the edge
+    /// aren't checked.
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridPosition};
+    ///
+    /// let floor: Grid<char> = "#..\n...".parse().unwrap();
+    /// let piece: Grid<char> = "##\n.#".parse().unwrap();
+    /// let clash = |here: &char, there: &char| *here == '#' && *there == '#';
+    /// assert!(floor.overlaps(&GridPosition::new(0, 0), &piece, clash));
+    /// assert!(!floor.overlaps(&GridPosition::new(1, 0), &piece, clash));
+    /// ```
+    pub fn overlaps<U>(
+        &self,
+        at: &GridPosition,
+        other: &Grid<U>,
+        mut predicate: impl FnMut(&T, &U) -> bool,
+    ) -> bool {
+        other.position_itr().any(|(p, cell)| {
+            self.get(&GridPosition::new(at.col + p.col, at.row + p.row))
+                .is_some_and(|here| predicate(here, cell))
+        })
+    }
+}
+
+impl<T: Clone + Ord> Grid<T> {
+    /// The same form for a grid however it's turned or mirrored: the least
+    /// of its [`Grid::orientations`], comparing size then cells row by row.
+    /// Handy for finding which shapes are really the same.
+    ///
+    /// ```
+    /// use aoclib::grid::Grid;
+    ///
+    /// let l: Grid<char> = "#.\n##".parse().unwrap();
+    /// let turned: Grid<char> = "##\n.#".parse().unwrap();
+    /// assert_eq!(l.canonical(), turned.canonical());
+    /// ```
+    pub fn canonical(&self) -> Grid<T> {
+        self.orientations()
+            .min_by(|a, b| (a.height, a.width, &a.cells).cmp(&(b.height, b.width, &b.cells)))
+            .expect("there are always eight orientations")
     }
 }
 
@@ -222,9 +1007,11 @@ where
     T: Clone,
 {
     fn flip(&self) -> Self {
-        let mut rows = self.rows.clone();
-        rows.reverse();
-        Grid { rows }
+        Grid {
+            cells: self.rows().rev().flatten().cloned().collect(),
+            width: self.width,
+            height: self.height,
+        }
     }
 }
 
@@ -292,39 +1079,18 @@ impl FromChar for usize {
     }
 }
 
-impl<T> FromStr for Grid<T>
-where
-    T: FromChar,
-{
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let rows = s
-            .lines()
-            .map(|l| l.chars().filter_map(|c| T::from_char(c).ok()).collect())
-            .collect();
-
-        Ok(Grid { rows })
-    }
-}
-
 impl<T> fmt::Display for Grid<T>
 where
     T: fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut result = fmt::Result::Ok(());
-        let plane: Plane = self.into();
-        let width = plane.width();
-        let height = plane.height();
-        for row in 0..height {
-            for col in 0..width {
-                let item = &self.rows[row as usize][col as usize];
-                result = result.and_then(|_| item.fmt(f));
+        for row in self.rows() {
+            for item in row {
+                item.fmt(f)?;
             }
-            result = result.and_then(|_| f.write_str(input::NEW_LINE));
+            f.write_str(input::NEW_LINE)?;
         }
-        result
+        Ok(())
     }
 }
 
@@ -336,10 +1102,11 @@ impl Flip for u32 {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
 
     use crate::{grid::Grid, input::*};
 
-    use super::{Flip, GridPosition};
+    use super::{Direction, Flip, GridPosition, GridRect};
 
     #[test]
     fn test_empty_line_chunks() {
@@ -351,12 +1118,8 @@ mod tests {
 
     #[test]
     fn test_transpose() {
-        let g = Grid {
-            rows: vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]],
-        };
-        let expected = Grid {
-            rows: vec![vec![1, 5], vec![2, 6], vec![3, 7], vec![4, 8]],
-        };
+        let g = Grid::new(vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]]);
+        let expected = Grid::new(vec![vec![1, 5], vec![2, 6], vec![3, 7], vec![4, 8]]);
 
         assert_eq!(expected, g.transpose());
     }
@@ -369,12 +1132,8 @@ mod tests {
 
     #[test]
     fn test_flip() {
-        let g = Grid {
-            rows: vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]],
-        };
-        let expected = Grid {
-            rows: vec![vec![5, 6, 7, 8], vec![1, 2, 3, 4]],
-        };
+        let g = Grid::new(vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]]);
+        let expected = Grid::new(vec![vec![5, 6, 7, 8], vec![1, 2, 3, 4]]);
 
         println!("{}", expected);
 
@@ -383,9 +1142,7 @@ mod tests {
 
     #[test]
     fn test_iter_right() {
-        let g = Grid {
-            rows: vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
-        };
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
         let right: Vec<_> = g
             .right_from(GridPosition::new(0, 0))
             .map(|(_, b)| b)
@@ -396,9 +1153,7 @@ mod tests {
 
     #[test]
     fn test_iter_left() {
-        let g = Grid {
-            rows: vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
-        };
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
         let right: Vec<_> = g
             .left_from(GridPosition::new(2, 0))
             .map(|(_, b)| b)
@@ -409,9 +1164,7 @@ mod tests {
 
     #[test]
     fn test_iter_up() {
-        let g = Grid {
-            rows: vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
-        };
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
         let right: Vec<_> = g
             .up_from(GridPosition::new(0, 2))
             .map(|(_, b)| b)
@@ -422,9 +1175,7 @@ mod tests {
 
     #[test]
     fn test_iter_down() {
-        let g = Grid {
-            rows: vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
-        };
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
         let right: Vec<_> = g
             .down_from(GridPosition::new(1, 0))
             .map(|(_, b)| b)
@@ -432,4 +1183,293 @@ mod tests {
             .collect();
         assert_eq!(vec![2, 5, 8], right);
     }
+
+    #[test]
+    fn test_row_col() {
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(&[4, 5, 6], g.row(1));
+        assert_eq!(vec![&2, &5], g.col(1).collect::<Vec<_>>());
+        assert_eq!(vec![&6, &3], g.col(2).rev().collect::<Vec<_>>());
+        assert_eq!(2, g.rows().len());
+    }
+
+    #[test]
+    fn test_get() {
+        let mut g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(Some(&6), g.get(&GridPosition::new(2, 1)));
+        assert_eq!(None, g.get(&GridPosition::new(3, 0)));
+        assert_eq!(None, g.get(&GridPosition::new(0, 2)));
+
+        g[GridPosition::new(0, 1)] = 9;
+        assert_eq!(9, g[GridPosition::new(0, 1)]);
+    }
+
+    #[test]
+    fn test_rotate_cw() {
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let expected = Grid::new(vec![vec![4, 1], vec![5, 2], vec![6, 3]]);
+        assert_eq!(expected, g.rotate_cw());
+    }
+
+    #[test]
+    fn test_parse() {
+        let g: Grid<char> = "ab\ncd\n\n".parse().unwrap();
+        assert_eq!((2, 2), (g.width(), g.height()));
+        assert_eq!("ab\ncd\n", g.to_string());
+
+        let err = "ab\nc".parse::<Grid<char>>().unwrap_err();
+        assert!(err.to_string().starts_with("line 2"), "{err}");
+    }
+
+    #[test]
+    fn test_step() {
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let origin = GridPosition::new(0, 0);
+
+        assert_eq!(None, g.checked_up(&origin));
+        assert_eq!(None, g.checked_left(&origin));
+        assert_eq!(
+            Some(GridPosition::new(1, 1)),
+            g.step(&origin, Direction::DownRight)
+        );
+        assert_eq!(None, g.checked_down(&GridPosition::new(0, 1)));
+        assert_eq!(None, g.checked_right(&GridPosition::new(2, 0)));
+
+        // without a grid only going below zero is caught
+        assert_eq!(None, origin.step(Direction::UpRight));
+        assert_eq!(
+            Some(GridPosition::new(3, 0)),
+            GridPosition::new(2, 0).step(Direction::Right)
+        );
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Up, Direction::Left.turn_right());
+        assert_eq!(Direction::DownLeft, Direction::DownRight.turn_right());
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(Direction::UpLeft, Direction::DownRight.opposite());
+
+        let diagonals = Direction::ALL.iter().filter(|d| d.is_diagonal()).count();
+        assert_eq!(4, diagonals);
+        assert!(Direction::ORTHOGONAL.iter().all(|d| !d.is_diagonal()));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let values =
+            |n: Vec<(GridPosition, &i32)>| n.into_iter().map(|(_, v)| *v).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![2, 6, 8, 4],
+            values(g.neighbours4(&GridPosition::new(1, 1)).collect())
+        );
+        assert_eq!(
+            vec![2, 5, 4],
+            values(g.neighbours8(&GridPosition::new(0, 0)).collect())
+        );
+        assert_eq!(8, g.neighbours8(&GridPosition::new(1, 1)).count());
+    }
+
+    #[test]
+    fn test_neighbours_mut() {
+        let mut g = Grid::new(vec![vec![0; 3]; 3]);
+        for (_, cell) in g.neighbours8_mut(&GridPosition::new(2, 1)) {
+            *cell += 1;
+        }
+        for (_, cell) in g.neighbours4_mut(&GridPosition::new(0, 0)) {
+            *cell += 10;
+        }
+        assert_eq!(
+            Grid::new(vec![vec![0, 11, 1], vec![10, 1, 0], vec![0, 1, 1]]),
+            g
+        );
+
+        for (pos, _) in g.position_itr() {
+            let order: Vec<_> = g.neighbours8(&pos).map(|(p, _)| p).collect();
+            let order_mut: Vec<_> = g.clone().neighbours8_mut(&pos).map(|(p, _)| p).collect();
+            assert_eq!(order, order_mut);
+            let order: Vec<_> = g.neighbours4(&pos).map(|(p, _)| p).collect();
+            let order_mut: Vec<_> = g.clone().neighbours4_mut(&pos).map(|(p, _)| p).collect();
+            assert_eq!(order, order_mut);
+        }
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let g: Grid<u32> = "\
+        2199943210\n\
+        3987894921\n\
+        9856789892\n\
+        8767896789\n\
+        9899965678\
+        "
+        .parse()
+        .unwrap();
+
+        let basin = g.flood_fill(&GridPosition::new(2, 2), |h| *h != 9);
+        assert_eq!(14, basin.len());
+        assert_eq!(GridPosition::new(2, 2), basin[0]);
+        assert!(
+            g.flood_fill(&GridPosition::new(2, 0), |h| *h != 9)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_components() {
+        let g: Grid<char> = "\
+        AAAA\n\
+        BBCD\n\
+        BBCC\n\
+        EEEC\
+        "
+        .parse()
+        .unwrap();
+        let regions = g.components(|a, b| a == b);
+
+        let measured: Vec<_> = regions
+            .iter()
+            .map(|r| (g[r.cells()[0]], r.area(), r.perimeter(), r.sides()))
+            .collect();
+        assert_eq!(
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ],
+            measured
+        );
+        assert_eq!(2, regions.labels()[GridPosition::new(3, 2)]);
+        assert_eq!(3, regions[3].id());
+    }
+
+    #[test]
+    fn test_components_with_holes() {
+        let g: Grid<char> = "\
+        OOOOO\n\
+        OXOXO\n\
+        OOOOO\n\
+        OXOXO\n\
+        OOOOO\
+        "
+        .parse()
+        .unwrap();
+        let regions = g.components(|a, b| a == b);
+
+        assert_eq!(5, regions.len());
+        let outer = regions.region_at(&GridPosition::new(0, 0));
+        assert_eq!(
+            (21, 36, 20),
+            (outer.area(), outer.perimeter(), outer.sides())
+        );
+        let hole = regions.region_at(&GridPosition::new(3, 3));
+        assert_eq!((1, 4, 4), (hole.area(), hole.perimeter(), hole.sides()));
+    }
+
+    #[test]
+    fn test_rotations() {
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(
+            Grid::new(vec![vec![3, 6], vec![2, 5], vec![1, 4]]),
+            g.rotate_ccw()
+        );
+        assert_eq!(g.rotate_cw().rotate_cw().rotate_cw(), g.rotate_ccw());
+        assert_eq!(
+            Grid::new(vec![vec![6, 5, 4], vec![3, 2, 1]]),
+            g.rotate_180()
+        );
+        assert_eq!(Grid::new(vec![vec![3, 2, 1], vec![6, 5, 4]]), g.mirror_h());
+        assert_eq!(Grid::new(vec![vec![4, 5, 6], vec![1, 2, 3]]), g.mirror_v());
+        assert_eq!(g.rotate_180(), g.mirror_h().mirror_v());
+    }
+
+    #[test]
+    fn test_orientations() {
+        let f: Grid<char> = "##\n#.\n##\n.#".parse().unwrap();
+        let all: Vec<_> = f.orientations().collect();
+        assert_eq!(8, all.len());
+        assert_eq!(f, all[0]);
+        assert_eq!(8, all.iter().collect::<HashSet<_>>().len());
+        assert!(all.iter().all(|o| o.canonical() == f.canonical()));
+
+        let square: Grid<char> = "##\n##".parse().unwrap();
+        assert_eq!(1, square.orientations().collect::<HashSet<_>>().len());
+    }
+
+    #[test]
+    fn test_subgrid_and_paste() {
+        let mut g = Grid::new(vec![vec![0; 4]; 3]);
+        let piece = Grid::new(vec![vec![1, 2], vec![3, 4]]);
+
+        g.paste(&GridPosition::new(3, 1), &piece);
+        assert_eq!(
+            Grid::new(vec![vec![0, 0, 0, 0], vec![0, 0, 0, 1], vec![0, 0, 0, 3]]),
+            g
+        );
+        assert!(g.overlaps(&GridPosition::new(2, 0), &piece, |a, _| *a != 0));
+        assert!(!g.overlaps(&GridPosition::new(0, 0), &piece, |a, _| *a != 0));
+
+        g.paste(&GridPosition::new(1, 0), &piece);
+        let rect = GridRect::new(GridPosition::new(1, 0), 2, 2);
+        assert_eq!(Some(piece), g.subgrid(&rect));
+        assert!(rect.contains(&GridPosition::new(2, 1)));
+        assert!(!rect.contains(&GridPosition::new(3, 1)));
+        assert_eq!(
+            None,
+            g.subgrid(&GridRect::new(GridPosition::new(3, 0), 2, 1))
+        );
+    }
+
+    #[test]
+    fn test_rays() {
+        let g: Grid<char> = "\
+        a..b\n\
+        .#..\n\
+        ..c.\
+        "
+        .parse()
+        .unwrap();
+        let corner = GridPosition::new(3, 2);
+
+        let c = GridPosition::new(2, 2);
+        let cells: String = g.ray(&c, Direction::UpLeft).map(|(_, c)| *c).collect();
+        assert_eq!("#a", cells);
+        assert_eq!(0, g.ray(&corner, Direction::Right).count());
+        assert_eq!(
+            Some((GridPosition::new(1, 1), &'#')),
+            g.first_hit(&c, Direction::UpLeft, |c| *c != '.')
+        );
+        assert_eq!(None, g.first_hit(&corner, (-3, -1), |c| *c == 'a'));
+
+        let wrapped: String = g
+            .ray_wrapping(&corner, (-1, 1))
+            .take(5)
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(".#.b.", wrapped);
+
+        let empty: Grid<char> = Grid::new(vec![]);
+        assert_eq!(0, empty.ray_wrapping(&corner, (1, 0)).count());
+    }
+
+    #[test]
+    #[should_panic(expected = "ragged grid: row 1 has 1 cells but row 0 has 2")]
+    fn test_ragged_rows() {
+        Grid::new(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_zero_width_rows() {
+        let g: Grid<u8> = Grid::new(vec![vec![], vec![]]);
+        assert_eq!((0, 2), (g.width(), g.height()));
+        assert_eq!(g.height(), g.rows().len());
+        assert!(g.rows().all(|row| row.is_empty()));
+        assert_eq!(2, g.mirror_v().rows().len());
+    }
 }
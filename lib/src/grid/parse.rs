@@ -0,0 +1,338 @@
+use core::fmt;
+use std::{collections::BTreeMap, ops::Index, str::FromStr};
+
+use super::{FromChar, Grid, GridPosition};
+
+/// Why some text couldn't be read as a [`Grid`], and where
+///
+/// ```
+/// use aoclib::grid::{Grid, GridParseError, GridParseProblem};
+///
+/// let err = "12\n3x".parse::<Grid<u32>>().unwrap_err();
+/// assert_eq!((2, 2, Some('x')), (err.line, err.col, err.char));
+/// assert_eq!(GridParseProblem::Cell("bad digit x".to_owned()), err.source);
+/// assert_eq!("line 2 col 2: bad cell 'x', bad digit x", err.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridParseError<E> {
+    /// Counting from 1, or 0 when there was nothing to parse
+    pub line: usize,
+    /// Counting from 1, the first column past the end for a short line
+    pub col: usize,
+    /// What was found there, `None` when a line ended early or the input
+    /// was empty
+    pub char: Option<char>,
+    pub source: GridParseProblem<E>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridParseProblem<E> {
+    /// [`FromChar`] turned the character down, with its reason
+    Cell(E),
+    /// The line is a different length from the first
+    Ragged { expected: usize },
+    /// No lines at all
+    Empty,
+    /// A marker given to [`Grid::parse_marked`] wasn't anywhere in the grid
+    MissingMarker(char),
+    /// A marker turned up a second time, `first` being where it was before
+    DuplicateMarker { first: GridPosition },
+}
+
+impl<E: fmt::Display> fmt::Display for GridParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            GridParseProblem::Cell(e) => write!(
+                f,
+                "line {} col {}: bad cell '{}', {e}",
+                self.line,
+                self.col,
+                self.char.unwrap_or_default()
+            ),
+            GridParseProblem::Ragged { expected } => write!(
+                f,
+                "line {} col {}: expected {expected} cells like the first line",
+                self.line, self.col
+            ),
+            GridParseProblem::Empty => f.write_str("no grid in empty input"),
+            GridParseProblem::MissingMarker(marker) => {
+                write!(f, "no marker '{marker}' in the grid")
+            }
+            GridParseProblem::DuplicateMarker { first } => write!(
+                f,
+                "line {} col {}: marker '{}' already found at line {} col {}",
+                self.line,
+                self.col,
+                self.char.unwrap_or_default(),
+                first.row + 1,
+                first.col + 1
+            ),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for GridParseError<E> {}
+
+/// So days with `String` errors can still use `?`
+impl<E: fmt::Display> From<GridParseError<E>> for String {
+    fn from(e: GridParseError<E>) -> Self {
+        e.to_string()
+    }
+}
+
+impl<T: FromChar> Grid<T> {
+    /// Reads a grid like [`str::parse`] does but skips any character that
+    /// isn't a cell, eg: stray spaces. Lines still need to end up the same
+    /// length, and empty input gives an empty grid.
+    ///
+    /// ```
+    /// use aoclib::grid::Grid;
+    ///
+    /// let g = Grid::<u32>::parse_lenient("1 2\n3 4").unwrap();
+    /// assert_eq!(Grid::new(vec![vec![1, 2], vec![3, 4]]), g);
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, GridParseError<T::Err>> {
+        let rows: Vec<Vec<T>> = lines(s)
+            .map(|l| l.chars().filter_map(|c| T::from_char(c).ok()).collect())
+            .collect();
+        check_widths(&rows, |_, _| None)?;
+        Ok(Grid::new(rows))
+    }
+}
+
+/// Reads one cell per character with [`FromChar`], a line per row.
+///
+/// Fails on the first character that isn't a cell, on lines of different
+/// lengths, and on empty input. Trailing newlines are ignored.
+impl<T: FromChar> FromStr for Grid<T> {
+    type Err = GridParseError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_cells(s, |_, c| T::from_char(c).map_err(GridParseProblem::Cell))
+    }
+}
+
+/// Where each marker was found by [`Grid::parse_marked`], every one asked
+/// for being there exactly once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Markers(BTreeMap<char, GridPosition>);
+
+impl Markers {
+    /// `None` only for a character that wasn't asked for
+    pub fn get(&self, marker: char) -> Option<GridPosition> {
+        self.0.get(&marker).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (char, GridPosition)> + '_ {
+        self.0.iter().map(|(c, p)| (*c, *p))
+    }
+}
+
+impl Index<char> for Markers {
+    type Output = GridPosition;
+
+    fn index(&self, marker: char) -> &Self::Output {
+        self.0
+            .get(&marker)
+            .unwrap_or_else(|| panic!("'{marker}' wasn't one of the markers"))
+    }
+}
+
+impl<T: FromChar> Grid<T> {
+    /// Parses like [`str::parse`] and also notes where each of `markers`
+    /// is, so start and end squares don't need finding afterwards. The
+    /// marker cells are still read with [`FromChar`].
+    ///
+    /// Each marker has to be in the grid exactly once.
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridParseProblem, GridPosition};
+    ///
+    /// let (g, at) = Grid::<char>::parse_marked("S.#\n.#E", &['S', 'E']).unwrap();
+    /// assert_eq!(GridPosition::new(0, 0), at['S']);
+    /// assert_eq!(GridPosition::new(2, 1), at['E']);
+    /// assert_eq!('E', g[at['E']]);
+    ///
+    /// let err = Grid::<char>::parse_marked("S..", &['S', 'E']).unwrap_err();
+    /// assert_eq!(GridParseProblem::MissingMarker('E'), err.source);
+    /// ```
+    pub fn parse_marked(
+        s: &str,
+        markers: &[char],
+    ) -> Result<(Self, Markers), GridParseError<T::Err>> {
+        Self::parse_marked_with(s, markers, T::from_char)
+    }
+
+    /// Like [`Grid::parse_marked`] but the marker cells are whatever
+    /// `replace` makes of them, eg: the floor a guard is standing on
+    ///
+    /// ```
+    /// use aoclib::grid::Grid;
+    ///
+    /// let (g, at) = Grid::<char>::parse_marked_with("..\n^.", &['^'], |_| Ok('.')).unwrap();
+    /// assert_eq!('.', g[at['^']]);
+    /// ```
+    pub fn parse_marked_with(
+        s: &str,
+        markers: &[char],
+        mut replace: impl FnMut(char) -> Result<T, T::Err>,
+    ) -> Result<(Self, Markers), GridParseError<T::Err>> {
+        let mut found = BTreeMap::new();
+        let grid = parse_cells(s, |p, c| {
+            if !markers.contains(&c) {
+                return T::from_char(c).map_err(GridParseProblem::Cell);
+            }
+            if let Some(first) = found.insert(c, p) {
+                return Err(GridParseProblem::DuplicateMarker { first });
+            }
+            replace(c).map_err(GridParseProblem::Cell)
+        })?;
+
+        match markers.iter().find(|m| !found.contains_key(m)) {
+            Some(missing) => Err(GridParseError {
+                line: 0,
+                col: 0,
+                char: Some(*missing),
+                source: GridParseProblem::MissingMarker(*missing),
+            }),
+            None => Ok((grid, Markers(found))),
+        }
+    }
+}
+
+/// The strict parse behind [`FromStr`], `cell` reading each character
+fn parse_cells<T, E>(
+    s: &str,
+    mut cell: impl FnMut(GridPosition, char) -> Result<T, GridParseProblem<E>>,
+) -> Result<Grid<T>, GridParseError<E>> {
+    let lines: Vec<&str> = lines(s).collect();
+    if lines.is_empty() {
+        return Err(GridParseError {
+            line: 0,
+            col: 0,
+            char: None,
+            source: GridParseProblem::Empty,
+        });
+    }
+
+    let rows = lines
+        .iter()
+        .enumerate()
+        .map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(col, c)| {
+                    cell(GridPosition::new(col, row), c).map_err(|source| GridParseError {
+                        line: row + 1,
+                        col: col + 1,
+                        char: Some(c),
+                        source,
+                    })
+                })
+                .collect::<Result<Vec<T>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    check_widths(&rows, |row, col| lines[row].chars().nth(col))?;
+    Ok(Grid::new(rows))
+}
+
+fn lines(s: &str) -> std::str::Lines<'_> {
+    s.trim_end_matches(['\r', '\n']).lines()
+}
+
+/// Fails on the first row that isn't as wide as the first, `char_at` giving
+/// the character past the expected width when the row is too long
+fn check_widths<T, E>(
+    rows: &[Vec<T>],
+    char_at: impl Fn(usize, usize) -> Option<char>,
+) -> Result<(), GridParseError<E>> {
+    let expected = rows.first().map_or(0, |r| r.len());
+    match rows.iter().position(|r| r.len() != expected) {
+        None => Ok(()),
+        Some(row) => {
+            let col = expected.min(rows[row].len());
+            Err(GridParseError {
+                line: row + 1,
+                col: col + 1,
+                char: char_at(row, col).filter(|_| rows[row].len() > expected),
+                source: GridParseProblem::Ragged { expected },
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let ragged = "123\n1234\n12".parse::<Grid<u32>>().unwrap_err();
+        assert_eq!(
+            GridParseError {
+                line: 2,
+                col: 4,
+                char: Some('4'),
+                source: GridParseProblem::Ragged { expected: 3 },
+            },
+            ragged
+        );
+
+        let short = "123\n12\n".parse::<Grid<u32>>().unwrap_err();
+        assert_eq!((2, 3, None), (short.line, short.col, short.char));
+        assert_eq!(
+            "line 2 col 3: expected 3 cells like the first line",
+            short.to_string()
+        );
+
+        let empty = "\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(GridParseProblem::Empty, empty.source);
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let g = Grid::<u32>::parse_lenient("1.2\r\n3.4\r\n").unwrap();
+        assert_eq!(Grid::new(vec![vec![1, 2], vec![3, 4]]), g);
+        assert_eq!(0, Grid::<u32>::parse_lenient("").unwrap().width());
+
+        let ragged = Grid::<u32>::parse_lenient("1.2\n3..").unwrap_err();
+        assert_eq!((2, 2), (ragged.line, ragged.col));
+        assert!("1.2\n3.4".parse::<Grid<u32>>().is_err());
+    }
+
+    #[test]
+    fn test_parse_marked() {
+        let (g, at) =
+            Grid::<char>::parse_marked_with("#S.\n.E.\n..#", &['S', 'E'], |_| Ok('.')).unwrap();
+        assert_eq!(GridPosition::new(1, 0), at['S']);
+        assert_eq!(GridPosition::new(1, 1), at['E']);
+        assert_eq!(
+            vec![('E', at['E']), ('S', at['S'])],
+            at.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(None, at.get('#'));
+        assert_eq!(&'.', g.at(&at['S']));
+
+        let twice = Grid::<char>::parse_marked("S..\n..S", &['S']).unwrap_err();
+        assert_eq!(
+            GridParseError {
+                line: 2,
+                col: 3,
+                char: Some('S'),
+                source: GridParseProblem::DuplicateMarker {
+                    first: GridPosition::new(0, 0)
+                },
+            },
+            twice
+        );
+        assert_eq!(
+            "line 2 col 3: marker 'S' already found at line 1 col 1",
+            twice.to_string()
+        );
+
+        let unmarked = Grid::<u32>::parse_marked("12\n34", &[]).map(|(g, _)| g);
+        assert_eq!(Ok(Grid::new(vec![vec![1, 2], vec![3, 4]])), unmarked);
+        let missing = Grid::<char>::parse_marked("..", &['@']).unwrap_err();
+        assert_eq!("no marker '@' in the grid", missing.to_string());
+    }
+}
@@ -0,0 +1,223 @@
+use super::{Direction, Grid, GridPosition};
+
+/// Which ways round [`Grid::find_pattern`] looks for a pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientations {
+    /// Only as it is given
+    AsGiven,
+    /// Every one of its [`Grid::orientations`]
+    All,
+}
+
+/// How a pattern was turned to match: `turns` quarter turns clockwise,
+/// after mirroring left to right when `mirrored`. The same order as
+/// [`Grid::orientations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub mirrored: bool,
+    pub turns: u8,
+}
+
+impl Orientation {
+    pub const AS_GIVEN: Orientation = Orientation {
+        mirrored: false,
+        turns: 0,
+    };
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The top left position of every place `pattern` matches and the
+    /// orientation it matched in, a `None` in the pattern matching anything.
+    ///
+    /// With [`Orientations::All`] a pattern that looks the same turned or
+    /// mirrored is only matched in the first orientation that gives it, so
+    /// each place is found once. The position is the top left of the pattern
+    /// as it was turned. An empty pattern matches nowhere.
+    ///
+    /// ```
+    /// use aoclib::grid::{Grid, GridPosition, Orientation, Orientations};
+    ///
+    /// let g: Grid<char> = "M.S.\n.A.M\nM.S.\n...M".parse().unwrap();
+    /// let cross = "M.S\n.A.\nM.S"
+    ///     .parse::<Grid<char>>()
+    ///     .unwrap()
+    ///     .map(|c| (*c != '.').then_some(*c));
+    ///
+    /// assert_eq!(
+    ///     vec![(GridPosition::new(0, 0), Orientation::AS_GIVEN)],
+    ///     g.find_pattern(&cross, Orientations::AsGiven)
+    /// );
+    /// assert_eq!(1, g.find_pattern(&cross, Orientations::All).len());
+    /// ```
+    pub fn find_pattern(
+        &self,
+        pattern: &Grid<Option<T>>,
+        orientations: Orientations,
+    ) -> Vec<(GridPosition, Orientation)>
+    where
+        T: Clone,
+    {
+        if pattern.cells.is_empty() {
+            return vec![];
+        }
+        let mut turned: Vec<(Grid<Option<T>>, Orientation)> = vec![];
+        let candidates = match orientations {
+            Orientations::AsGiven => 1,
+            Orientations::All => 8,
+        };
+        for (i, g) in pattern.orientations().take(candidates).enumerate() {
+            if turned.iter().all(|(seen, _)| *seen != g) {
+                let orientation = Orientation {
+                    mirrored: i >= 4,
+                    turns: (i % 4) as u8,
+                };
+                turned.push((g, orientation));
+            }
+        }
+        turned
+            .iter()
+            .flat_map(|(g, orientation)| {
+                self.matches(g)
+                    .into_iter()
+                    .map(move |at| (at, *orientation))
+            })
+            .collect()
+    }
+
+    fn matches(&self, pattern: &Grid<Option<T>>) -> Vec<GridPosition> {
+        if pattern.width > self.width || pattern.height > self.height {
+            return vec![];
+        }
+        (0..=self.height - pattern.height)
+            .flat_map(|row| {
+                (0..=self.width - pattern.width).map(move |col| GridPosition::new(col, row))
+            })
+            .filter(|at| {
+                pattern.position_itr().all(|(p, wanted)| {
+                    wanted.as_ref().is_none_or(|w| {
+                        *w == self[GridPosition::new(at.col + p.col, at.row + p.row)]
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Where `word` starts and which way it reads, for every time it
+    /// appears in a straight line in any of the eight directions. A one
+    /// letter word reads [`Direction::Right`], so each cell is found once.
+    ///
+    /// ```
+    /// use aoclib::grid::{Direction, Grid, GridPosition};
+    ///
+    /// let g: Grid<char> = "XMAS\n.M..\n..A.\n...S".parse().unwrap();
+    /// let word: Vec<char> = "XMAS".chars().collect();
+    /// assert_eq!(
+    ///     vec![
+    ///         (GridPosition::new(0, 0), Direction::Right),
+    ///         (GridPosition::new(0, 0), Direction::DownRight),
+    ///     ],
+    ///     g.find_word(&word)
+    /// );
+    /// ```
+    pub fn find_word(&self, word: &[T]) -> Vec<(GridPosition, Direction)> {
+        let Some((first, rest)) = word.split_first() else {
+            return vec![];
+        };
+        self.position_itr()
+            .filter(|(_, cell)| *cell == first)
+            .flat_map(|(p, _)| {
+                let directions: &[Direction] = if rest.is_empty() {
+                    &[Direction::Right]
+                } else {
+                    &Direction::ALL
+                };
+                directions.iter().map(move |d| (p, *d))
+            })
+            .filter(|(p, d)| {
+                let mut ray = self.ray(p, *d);
+                rest.iter()
+                    .all(|letter| ray.next().is_some_and(|(_, cell)| cell == letter))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_word() {
+        let g: Grid<char> = "\
+        ..X...\n\
+        .SAMX.\n\
+        .A..A.\n\
+        XMAS.S\n\
+        .X....\
+        "
+        .parse()
+        .unwrap();
+        let xmas: Vec<char> = "XMAS".chars().collect();
+
+        assert_eq!(
+            vec![
+                (GridPosition::new(2, 0), Direction::DownRight),
+                (GridPosition::new(4, 1), Direction::Left),
+                (GridPosition::new(0, 3), Direction::Right),
+                (GridPosition::new(1, 4), Direction::Up),
+            ],
+            g.find_word(&xmas)
+        );
+        assert!(g.find_word(&['X', 'M', 'A', 'S', 'X']).is_empty());
+        assert!(g.find_word(&[]).is_empty());
+        let a: Vec<_> = [(2, 1), (1, 2), (4, 2), (2, 3)]
+            .map(|(col, row)| (GridPosition::new(col, row), Direction::Right))
+            .into();
+        assert_eq!(a, g.find_word(&['A']));
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let g = Grid::new(vec![vec![1, 2, 1, 2], vec![3, 1, 3, 1], vec![1, 2, 1, 2]]);
+        let pattern = Grid::new(vec![vec![Some(1), None], vec![None, Some(1)]]);
+
+        assert_eq!(
+            vec![
+                (GridPosition::new(0, 0), Orientation::AS_GIVEN),
+                (GridPosition::new(2, 0), Orientation::AS_GIVEN),
+                (GridPosition::new(1, 1), Orientation::AS_GIVEN),
+            ],
+            g.find_pattern(&pattern, Orientations::AsGiven)
+        );
+        let too_big = Grid::new(vec![vec![None; 5]]);
+        assert!(g.find_pattern(&too_big, Orientations::All).is_empty());
+        let empty: Grid<Option<i32>> = Grid::new(vec![]);
+        assert!(g.find_pattern(&empty, Orientations::All).is_empty());
+    }
+
+    #[test]
+    fn test_find_pattern_turned() {
+        let g = Grid::new(vec![vec![1, 2, 0], vec![0, 0, 2], vec![0, 0, 1]]);
+        let pattern = Grid::new(vec![vec![Some(1), Some(2)]]);
+
+        assert_eq!(
+            vec![(GridPosition::new(0, 0), Orientation::AS_GIVEN)],
+            g.find_pattern(&pattern, Orientations::AsGiven)
+        );
+        // turned a quarter it reads down, so the 2 above the 1 is found by
+        // three turns and the mirrored pattern is never needed
+        assert_eq!(
+            vec![
+                (GridPosition::new(0, 0), Orientation::AS_GIVEN),
+                (
+                    GridPosition::new(2, 1),
+                    Orientation {
+                        mirrored: false,
+                        turns: 3
+                    }
+                ),
+            ],
+            g.find_pattern(&pattern, Orientations::All)
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use core::fmt;
+
+use hashbrown::HashMap;
+
+use crate::cartesian::Point;
+
+use super::{Grid, GridPosition};
+
+/// A grid without edges, for simulations that spread out as they go: sand
+/// piling up, a rope wandering about, an image growing each step.
+///
+/// Only cells that have been set are kept, every other cell reads as the
+/// background. `y` grows down the page like the rows of a [`Grid`], and
+/// either coordinate can go negative.
+///
+/// ```
+/// use aoclib::{cartesian::Point, grid::SparseGrid};
+///
+/// let mut sand = SparseGrid::new('.');
+/// sand.insert(Point::new(500, 0), '+');
+/// sand.insert(Point::new(498, 2), 'o');
+/// assert_eq!(&'.', sand.get(&Point::new(-3, 7)));
+/// assert_eq!("..+\n...\no..\n", sand.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    background: T,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(background: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            background,
+        }
+    }
+
+    /// What every cell that hasn't been set reads as
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    /// Changes what unset cells read as, eg: when an infinite image's
+    /// surroundings all light up at once. Cells that were set keep their
+    /// values.
+    pub fn set_background(&mut self, background: T) -> T {
+        std::mem::replace(&mut self.background, background)
+    }
+
+    /// The cell at `p`, the background when it hasn't been set
+    pub fn get(&self, p: &Point) -> &T {
+        self.cells.get(p).unwrap_or(&self.background)
+    }
+
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    /// Puts `p` back to the background
+    pub fn remove(&mut self, p: &Point) -> Option<T> {
+        self.cells.remove(p)
+    }
+
+    /// Whether `p` has been set, even to the same value as the background
+    pub fn contains(&self, p: &Point) -> bool {
+        self.cells.contains_key(p)
+    }
+
+    /// How many cells have been set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells that have been set, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&Point, &T)> {
+        self.cells.iter()
+    }
+
+    /// The least and greatest corners of the area holding every set cell,
+    /// `None` while nothing has been set
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = points.next()?;
+        let (min, max) = points.fold((first.clone(), first.clone()), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+        Some((min, max))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// The cell at `p` to change in place, setting it to the background
+    /// first when it hasn't been set, like [`SparseGrid::get`] would read it
+    pub fn get_mut(&mut self, p: &Point) -> &mut T {
+        self.cells
+            .entry(p.clone())
+            .or_insert_with(|| self.background.clone())
+    }
+
+    /// The area within [`SparseGrid::bounds`] as a [`Grid`], its top left
+    /// cell being the least corner. Empty when nothing has been set.
+    pub fn to_grid(&self) -> Grid<T> {
+        let Some((min, max)) = self.bounds() else {
+            return Grid::new(vec![]);
+        };
+        let rows = (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| self.get(&Point::new(x, y)).clone())
+                    .collect()
+            })
+            .collect();
+        Grid::new(rows)
+    }
+}
+
+impl<T: PartialEq> SparseGrid<T> {
+    /// Every cell of `grid` that isn't `background`, with the top left cell
+    /// at `(0, 0)`
+    pub fn from_grid(grid: Grid<T>, background: T) -> Self {
+        let width = grid.width();
+        let cells = grid
+            .cells
+            .into_iter()
+            .enumerate()
+            .filter(|(_, cell)| *cell != background)
+            .map(|(i, cell)| (Point::new((i % width) as i64, (i / width) as i64), cell))
+            .collect();
+        SparseGrid { cells, background }
+    }
+}
+
+impl<T: PartialEq + Default> From<Grid<T>> for SparseGrid<T> {
+    /// [`SparseGrid::from_grid`] with the default value as the background
+    fn from(grid: Grid<T>) -> Self {
+        SparseGrid::from_grid(grid, T::default())
+    }
+}
+
+impl From<GridPosition> for Point {
+    fn from(value: GridPosition) -> Self {
+        Point::new(value.col as i64, value.row as i64)
+    }
+}
+
+/// Prints the area within [`SparseGrid::bounds`], nothing when it's empty
+impl<T> fmt::Display for SparseGrid<T>
+where
+    T: fmt::Display + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        self.to_grid().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_and_grid() {
+        let mut g = SparseGrid::new(0);
+        assert_eq!(None, g.bounds());
+        assert_eq!(Grid::new(vec![]), g.to_grid());
+
+        g.insert(Point::new(-1, 2), 5);
+        g.insert(Point::new(1, 1), 7);
+        assert_eq!(Some((Point::new(-1, 1), Point::new(1, 2))), g.bounds());
+        assert_eq!(Grid::new(vec![vec![0, 0, 7], vec![5, 0, 0]]), g.to_grid());
+
+        g.remove(&Point::new(1, 1));
+        assert_eq!(Some((Point::new(-1, 2), Point::new(-1, 2))), g.bounds());
+    }
+
+    #[test]
+    fn test_background() {
+        let mut g = SparseGrid::new(false);
+        g.insert(Point::new(0, 0), true);
+        g.insert(Point::new(2, 0), false);
+
+        assert!(!g.get(&Point::new(100, -100)));
+        assert!(!g.set_background(true));
+        assert!(g.get(&Point::new(100, -100)));
+        assert!(!g.get(&Point::new(2, 0)));
+        assert_eq!(2, g.len());
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut g = SparseGrid::new(1);
+        *g.get_mut(&Point::new(3, -4)) += 1;
+        assert_eq!(&2, g.get(&Point::new(3, -4)));
+
+        assert_eq!(&mut 1, g.get_mut(&Point::new(0, 0)));
+        assert!(g.contains(&Point::new(0, 0)));
+        assert_eq!(2, g.len());
+    }
+
+    #[test]
+    fn test_grid_round_trip() {
+        let grid: Grid<char> = "#..\n.#.\n..#".parse().unwrap();
+        let sparse = SparseGrid::from_grid(grid.clone(), '.');
+
+        assert_eq!(3, sparse.len());
+        assert_eq!(&'#', sparse.get(&GridPosition::new(1, 1).into()));
+        assert_eq!(grid, sparse.to_grid());
+        assert_eq!("#..\n.#.\n..#\n", sparse.to_string());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::cartesian::Point;
+
+use super::{Direction, Grid, GridPosition};
+
+/// A grid repeated forever in every direction, for maps that wrap round
+/// at the edges or tile out to infinity.
+///
+/// Positions are signed with `y` growing down like the rows of a [`Grid`],
+/// and `(0, 0)` is the top left cell of the original. Each copy of the grid
+/// is a tile: the original is tile `(0, 0)`, the copy to its left `(-1, 0)`.
+///
+/// ```
+/// use aoclib::{cartesian::Point, grid::Grid};
+///
+/// let g: Grid<char> = "ab\ncd".parse().unwrap();
+/// let tiled = g.wrapping();
+/// assert_eq!((&'d', Point::new(-1, 1)), tiled.get(&Point::new(-1, 3)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wrapping<G>(G);
+
+impl<T> Grid<T> {
+    /// This grid tiled out forever, see [`Wrapping`]
+    pub fn wrapping(&self) -> Wrapping<&Grid<T>> {
+        Wrapping(self)
+    }
+}
+
+impl<'a, T> Wrapping<&'a Grid<T>> {
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.0
+    }
+
+    /// Where `p` lands on the original grid, and which tile it's in
+    pub fn locate(&self, p: &Point) -> (GridPosition, Point) {
+        assert!(
+            self.0.width > 0 && self.0.height > 0,
+            "an empty grid can't be tiled"
+        );
+        let (width, height) = (self.0.width as i64, self.0.height as i64);
+        let pos = GridPosition::new(
+            p.x.rem_euclid(width) as usize,
+            p.y.rem_euclid(height) as usize,
+        );
+        (
+            pos,
+            Point::new(p.x.div_euclid(width), p.y.div_euclid(height)),
+        )
+    }
+
+    /// The cell at `p` and the tile it's in
+    pub fn get(&self, p: &Point) -> (&'a T, Point) {
+        let (pos, tile) = self.locate(p);
+        (&self.0[pos], tile)
+    }
+
+    /// Moves `p` one step, which on an endless grid always lands somewhere
+    pub fn step(&self, p: &Point, direction: Direction) -> Point {
+        let (cols, rows) = direction.offset();
+        Point::new(p.x + cols as i64, p.y + rows as i64)
+    }
+
+    /// The positions sharing an edge with `p` and their cells, clockwise
+    /// from up
+    pub fn neighbours4(&self, p: &Point) -> impl Iterator<Item = (Point, &'a T)> {
+        self.neighbours(p, &Direction::ORTHOGONAL)
+    }
+
+    /// Like [`Wrapping::neighbours4`] but including the diagonals
+    pub fn neighbours8(&self, p: &Point) -> impl Iterator<Item = (Point, &'a T)> {
+        self.neighbours(p, &Direction::ALL)
+    }
+
+    fn neighbours(
+        &self,
+        p: &Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> {
+        let (view, p) = (*self, p.clone());
+        directions.iter().map(move |d| {
+            let n = view.step(&p, *d);
+            let (cell, _) = view.get(&n);
+            (n, cell)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let tiled = g.wrapping();
+
+        assert_eq!(
+            (GridPosition::new(2, 1), Point::new(0, 0)),
+            tiled.locate(&Point::new(2, 1))
+        );
+        assert_eq!(
+            (GridPosition::new(2, 1), Point::new(-1, -1)),
+            tiled.locate(&Point::new(-1, -1))
+        );
+        assert_eq!(
+            (GridPosition::new(0, 0), Point::new(-4, 3)),
+            tiled.locate(&Point::new(-12, 6))
+        );
+        assert_eq!((&4, Point::new(1, 0)), tiled.get(&Point::new(3, 1)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let around: Vec<_> = g
+            .wrapping()
+            .neighbours4(&Point::new(0, 0))
+            .map(|(p, n)| (p.x, p.y, *n))
+            .collect();
+        assert_eq!(vec![(0, -1, 4), (1, 0, 2), (0, 1, 4), (-1, 0, 3)], around);
+        assert_eq!(8, g.wrapping().neighbours8(&Point::new(0, 0)).count());
+    }
+}
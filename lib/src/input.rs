@@ -1,3 +1,10 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
 #[cfg(windows)]
 pub const NEW_LINE: &'static str = "\r\n";
 
@@ -14,6 +21,122 @@ pub fn empty_line_chunks<'a>(input: &'a str) -> std::str::Split<'a, &'a str> {
     input.split(EMPTY_LINE)
 }
 
+/// Environment variable pointing at a puzzle input to use instead of the default path.
+/// Takes the same value as the `--input` flag, and like it only applies when a single
+/// day is run: [`load`], and so `aoc all`, `aoc-bench` and tests, always use the
+/// day's own input.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// Nothing at the resolved path. Inputs aren't committed, so this is
+    /// expected on a fresh clone until each day's input is downloaded.
+    Missing(PathBuf),
+    Unreadable(PathBuf, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "no puzzle input at {}, save it there or pass --input <path>",
+                path.display()
+            ),
+            InputError::Unreadable(path, e) => {
+                write!(f, "can't read puzzle input {}: {e}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing(_) => None,
+            InputError::Unreadable(_, e) => Some(e),
+        }
+    }
+}
+
+/// The workspace directory holding each year's crate
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoclib lives within the workspace")
+}
+
+/// The directory holding a day's solution and inputs, eg: `2024/d17`
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    workspace_root()
+        .join(year.to_string())
+        .join(format!("d{day}"))
+}
+
+/// Where a day keeps its puzzle input within the workspace, eg: `2024/d17/input.txt`
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("input.txt")
+}
+
+/// The input named by [`INPUT_VAR`], when it's set
+pub fn override_path() -> Option<PathBuf> {
+    env::var_os(INPUT_VAR).map(PathBuf::from)
+}
+
+/// Where a single day run reads its input: the path from [`INPUT_VAR`] when set,
+/// otherwise [`default_path`]
+pub fn path(year: u16, day: u8) -> PathBuf {
+    override_path().unwrap_or_else(|| default_path(year, day))
+}
+
+pub fn read(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+        _ => InputError::Unreadable(path.to_path_buf(), e),
+    })
+}
+
+/// Reads the puzzle input for a day from its [`default_path`].
+pub fn load(year: u16, day: u8) -> Result<String, InputError> {
+    read(default_path(year, day))
+}
+
+/// For binaries that run a single day: reads the input at [`path`], or says why
+/// it couldn't be read and exits.
+pub fn load_or_exit(year: u16, day: u8) -> String {
+    read(path(year, day)).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    })
+}
+
+/// For tests against the real puzzle input.
+///
+/// Returns `None`, after saying why, when the input hasn't been downloaded
+/// so the test can return early rather than fail.
+///
+/// ```ignore
+/// #[test]
+/// fn input_pt1() {
+///     let Some(test_input) = aoclib::input::load_or_skip(2024, 17) else {
+///         return;
+///     };
+///     assert_eq!("3,6,7,0,5,7,3,1,4", part1(&test_input));
+/// }
+/// ```
+pub fn load_or_skip(year: u16, day: u8) -> Option<String> {
+    match load(year, day) {
+        Ok(txt) => Some(txt),
+        Err(e @ InputError::Missing(_)) => {
+            // written directly so the test harness doesn't capture it
+            let _ = writeln!(io::stderr(), "skipping {year} day {day}: {e}");
+            None
+        }
+        Err(e) => panic!("{e}"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -26,4 +149,18 @@ mod tests {
         let chunks: Vec<_> = empty_line_chunks(text).collect();
         assert_eq!(vec!["first", "second", "third"], chunks);
     }
+
+    #[test]
+    fn test_default_path() {
+        let path = default_path(2024, 17);
+        assert!(path.ends_with("2024/d17/input.txt"));
+        assert!(path.ancestors().nth(3).unwrap().join("lib").is_dir());
+    }
+
+    #[test]
+    fn test_read_missing() {
+        let result = read(default_path(1999, 1));
+        assert!(matches!(result, Err(InputError::Missing(_))));
+        assert!(result.unwrap_err().to_string().contains("1999"));
+    }
 }
@@ -1,9 +1,18 @@
+pub mod answers;
+pub mod bench;
 pub mod cartesian;
+pub mod cartesian3;
 pub mod distance;
+pub mod examples;
 pub mod grid;
 pub mod input;
 pub mod neighbour;
 pub mod number;
+pub mod ocr;
 pub mod range;
+pub mod render;
 pub mod shortest_path;
+pub mod solution;
 pub mod timing;
+
+pub use solution::Answer;
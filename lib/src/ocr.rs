@@ -0,0 +1,288 @@
+//! Reading the block capitals some puzzles draw as their answer, so those
+//! days can be checked like any other.
+//!
+//! Both fonts the puzzles use are known: letters 4 cells wide and 6 high
+//! with a gap between each, and 6 wide and 10 high.
+//!
+//! ```
+//! use aoclib::{grid::Grid, ocr};
+//!
+//! let rows = ["#..#.####", "#..#.#...", "####.###.", "#..#.#...", "#..#.#...", "#..#.####"];
+//! let screen: Grid<char> = rows.join("\n").parse().unwrap();
+//! assert_eq!(Ok("HE".to_owned()), ocr::read_letters(&screen.map(|c| *c == '#')));
+//! ```
+
+use core::fmt;
+
+use crate::{
+    cartesian::Point,
+    grid::{Grid, SparseGrid},
+};
+
+/// Why some cells couldn't be read as letters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// The lit cells were this many rows high, which neither font is
+    Height(usize),
+    /// Letters that aren't in the font, drawn with `#` and `.`. What could
+    /// be read has a `?` for each of them.
+    Unknown { read: String, glyphs: Vec<String> },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Height(height) => {
+                write!(f, "letters are 6 or 10 cells high, not {height}")
+            }
+            OcrError::Unknown { read, glyphs } => {
+                write!(
+                    f,
+                    "read \"{read}\" but didn't know {} letters",
+                    glyphs.len()
+                )?;
+                for glyph in glyphs {
+                    write!(f, "\n\n{glyph}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// The letters drawn by the `true` cells of `screen`, left to right.
+///
+/// Any blank rows around the letters are ignored, and each letter is the
+/// lit columns between blank ones.
+pub fn read_letters(screen: &Grid<bool>) -> Result<String, OcrError> {
+    let lit_rows: Vec<usize> = screen
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| row.contains(&true))
+        .map(|(i, _)| i)
+        .collect();
+    let (Some(top), Some(bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Ok(String::new());
+    };
+    let height = bottom - top + 1;
+    let font = match height {
+        6 => SMALL,
+        10 => LARGE,
+        _ => return Err(OcrError::Height(height)),
+    };
+
+    let rows: Vec<&[bool]> = screen.rows().skip(*top).take(height).collect();
+    let lit = |col: usize| rows.iter().any(|row| row[col]);
+    let mut glyphs = vec![];
+    let mut col = 0;
+    while col < screen.width() {
+        if !lit(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < screen.width() && lit(col) {
+            col += 1;
+        }
+        let drawing: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                row[start..col]
+                    .iter()
+                    .map(|on| if *on { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        glyphs.push(drawing.join("\n"));
+    }
+
+    let mut read = String::new();
+    let mut unknown = vec![];
+    for glyph in glyphs {
+        match font
+            .iter()
+            .find(|(_, rows)| rows.replace(' ', "\n") == glyph)
+        {
+            Some((letter, _)) => read.push(*letter),
+            None => {
+                read.push('?');
+                unknown.push(glyph);
+            }
+        }
+    }
+    if unknown.is_empty() {
+        Ok(read)
+    } else {
+        Err(OcrError::Unknown {
+            read,
+            glyphs: unknown,
+        })
+    }
+}
+
+/// [`read_letters`] for letters drawn as points, `y` growing down
+pub fn read_points<'a>(points: impl IntoIterator<Item = &'a Point>) -> Result<String, OcrError> {
+    let mut screen = SparseGrid::new(false);
+    for p in points {
+        screen.insert(p.clone(), true);
+    }
+    read_letters(&screen.to_grid())
+}
+
+/// Each letter's rows, top to bottom and split by spaces
+type Font = &'static [(char, &'static str)];
+
+const SMALL: Font = &[
+    ('A', ".##. #..# #..# #### #..# #..#"),
+    ('B', "###. #..# ###. #..# #..# ###."),
+    ('C', ".##. #..# #... #... #..# .##."),
+    ('E', "#### #... ###. #... #... ####"),
+    ('F', "#### #... ###. #... #... #..."),
+    ('G', ".##. #..# #... #.## #..# .###"),
+    ('H', "#..# #..# #### #..# #..# #..#"),
+    ('I', "### .#. .#. .#. .#. ###"),
+    ('J', "..## ...# ...# ...# #..# .##."),
+    ('K', "#..# #.#. ##.. #.#. #.#. #..#"),
+    ('L', "#... #... #... #... #... ####"),
+    ('O', ".##. #..# #..# #..# #..# .##."),
+    ('P', "###. #..# #..# ###. #... #..."),
+    ('R', "###. #..# #..# ###. #.#. #..#"),
+    ('S', ".### #... #... .##. ...# ###."),
+    ('U', "#..# #..# #..# #..# #..# .##."),
+    ('Y', "#...# #...# .#.#. ..#.. ..#.. ..#.."),
+    ('Z', "#### ...# ..#. .#.. #... ####"),
+];
+
+const LARGE: Font = &[
+    (
+        'A',
+        "..##.. .#..#. #....# #....# #....# ###### #....# #....# #....# #....#",
+    ),
+    (
+        'B',
+        "#####. #....# #....# #....# #####. #....# #....# #....# #....# #####.",
+    ),
+    (
+        'C',
+        ".####. #....# #..... #..... #..... #..... #..... #..... #....# .####.",
+    ),
+    (
+        'E',
+        "###### #..... #..... #..... #####. #..... #..... #..... #..... ######",
+    ),
+    (
+        'F',
+        "###### #..... #..... #..... #####. #..... #..... #..... #..... #.....",
+    ),
+    (
+        'G',
+        ".####. #....# #..... #..... #..... #..### #....# #....# #...## .###.#",
+    ),
+    (
+        'H',
+        "#....# #....# #....# #....# ###### #....# #....# #....# #....# #....#",
+    ),
+    (
+        'J',
+        "...### ....#. ....#. ....#. ....#. ....#. ....#. #...#. #...#. .###..",
+    ),
+    (
+        'K',
+        "#....# #...#. #..#.. #.#... ##.... ##.... #.#... #..#.. #...#. #....#",
+    ),
+    (
+        'L',
+        "#..... #..... #..... #..... #..... #..... #..... #..... #..... ######",
+    ),
+    (
+        'N',
+        "#....# ##...# ##...# #.#..# #.#..# #..#.# #..#.# #...## #...## #....#",
+    ),
+    (
+        'P',
+        "#####. #....# #....# #....# #####. #..... #..... #..... #..... #.....",
+    ),
+    (
+        'R',
+        "#####. #....# #....# #....# #####. #..#.. #...#. #...#. #....# #....#",
+    ),
+    (
+        'X',
+        "#....# #....# .#..#. .#..#. ..##.. ..##.. .#..#. .#..#. #....# #....#",
+    ),
+    (
+        'Z',
+        "###### .....# .....# ....#. ...#.. ..#... .#.... #..... #..... ######",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(drawing: &str) -> Grid<bool> {
+        drawing.parse::<Grid<char>>().unwrap().map(|c| *c == '#')
+    }
+
+    #[test]
+    fn test_read_small() {
+        let s = screen(
+            "\
+            ...........\n\
+            .###...##..\n\
+            .#..#.#..#.\n\
+            .#..#.#....\n\
+            .###..#.##.\n\
+            .#.#..#..#.\n\
+            .#..#..###.\n\
+            ...........",
+        );
+        assert_eq!(Ok("RG".to_owned()), read_letters(&s));
+
+        let points: Vec<Point> = [(0, 0), (2, 0), (0, 1), (2, 1), (0, 2), (1, 2)]
+            .into_iter()
+            .map(Point::from)
+            .collect();
+        assert_eq!(Err(OcrError::Height(3)), read_points(&points));
+        assert_eq!(Ok(String::new()), read_letters(&screen("....")));
+    }
+
+    fn row(glyph: &str, i: usize) -> &str {
+        glyph.split(' ').nth(i).unwrap()
+    }
+
+    #[test]
+    fn test_read_large() {
+        let rows: Vec<String> = (0..10)
+            .map(|i| format!("{}..{}", row(LARGE[6].1, i), row(LARGE[13].1, i)))
+            .collect();
+        assert_eq!(Ok("HX".to_owned()), read_letters(&screen(&rows.join("\n"))));
+    }
+
+    #[test]
+    fn test_unknown_glyphs() {
+        let s = screen(
+            "\
+            #..#.#.#\n\
+            #..#.#.#\n\
+            ####.#.#\n\
+            #..#.#.#\n\
+            #..#.#.#\n\
+            #..#.#.#",
+        );
+        let err = read_letters(&s).unwrap_err();
+        assert_eq!(
+            OcrError::Unknown {
+                read: "H??".to_owned(),
+                glyphs: vec!["#\n#\n#\n#\n#\n#".to_owned(); 2],
+            },
+            err
+        );
+        assert!(
+            err.to_string()
+                .starts_with("read \"H??\" but didn't know 2 letters")
+        );
+    }
+}
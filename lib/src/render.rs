@@ -0,0 +1,237 @@
+//! Drawing a [`Grid`] with things marked on it, for looking at while
+//! debugging.
+//!
+//! ```
+//! use aoclib::{grid::{Grid, GridPosition}, render::render};
+//!
+//! let g: Grid<char> = "S..\n.#.\n..E".parse().unwrap();
+//! let route = [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)].map(|(c, r)| GridPosition::new(c, r));
+//! let drawn = render(&g).highlight([GridPosition::new(0, 2)], '*').steps(route);
+//! assert_eq!(">>v\n.#v\n*.E\n", drawn.to_string());
+//! ```
+
+use core::fmt;
+
+use hashbrown::HashMap;
+
+use crate::{
+    grid::{Direction, Grid, GridPosition},
+    input,
+    shortest_path::Path,
+};
+
+mod image;
+
+pub use image::{Format, Frames, Image, Rgb};
+
+/// Starts drawing `grid`, see [`Render`] for what can go on top
+pub fn render<T: fmt::Display>(grid: &Grid<T>) -> Render<'_, T> {
+    Render {
+        grid,
+        marks: HashMap::new(),
+        layers: 0,
+        colour: false,
+        rulers: false,
+    }
+}
+
+/// A [`Grid`] with highlights and paths drawn over it, written out with
+/// [`fmt::Display`].
+///
+/// Later highlights and paths are drawn over earlier ones. Rulers assume
+/// every cell is written as one character.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    marks: HashMap<GridPosition, Mark>,
+    layers: usize,
+    colour: bool,
+    rulers: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Mark {
+    glyph: char,
+    ansi: &'static str,
+}
+
+/// Colours given to each call to [`Render::highlight`] in turn
+const HIGHLIGHTS: [&str; 5] = ["31", "32", "34", "35", "36"];
+const PATH: &str = "1;33";
+
+impl<T: fmt::Display> Render<'_, T> {
+    /// Draws `mark` over every one of `positions` on the grid, in the next
+    /// colour when colour is on
+    pub fn highlight(
+        mut self,
+        positions: impl IntoIterator<Item = GridPosition>,
+        mark: char,
+    ) -> Self {
+        let ansi = HIGHLIGHTS[self.layers % HIGHLIGHTS.len()];
+        self.layers += 1;
+        for p in positions {
+            self.marks.insert(p, Mark { glyph: mark, ansi });
+        }
+        self
+    }
+
+    /// Draws an arrow on each of `positions` pointing to the one after,
+    /// leaving the last as it is so the goal still shows. Steps that don't
+    /// go to a neighbour are drawn as `*`.
+    pub fn steps(mut self, positions: impl IntoIterator<Item = GridPosition>) -> Self {
+        let positions: Vec<GridPosition> = positions.into_iter().collect();
+        for pair in positions.windows(2) {
+            let glyph = direction(&pair[0], &pair[1]).map_or('*', arrow);
+            self.marks.insert(pair[0], Mark { glyph, ansi: PATH });
+        }
+        self
+    }
+
+    /// Draws a path found by [`crate::shortest_path::astar`] from `start` as
+    /// [`Render::steps`]. Those paths leave out where they started, so it's
+    /// put back on the front to draw the first move.
+    pub fn path<C>(self, start: GridPosition, path: &Path<GridPosition, C>) -> Self {
+        self.steps(std::iter::once(start).chain(path.path.iter().rev().map(|(p, _)| *p)))
+    }
+
+    /// Writes marks in ANSI colours, for a terminal
+    pub fn colour(mut self, on: bool) -> Self {
+        self.colour = on;
+        self
+    }
+
+    /// Numbers the columns across the top and the rows down the left
+    pub fn rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    fn write_column_rulers(&self, f: &mut fmt::Formatter<'_>, margin: usize) -> fmt::Result {
+        let places = digits(self.grid.width().saturating_sub(1));
+        for place in (0..places).rev() {
+            let unit = 10usize.pow(place as u32);
+            let line: String = (0..self.grid.width())
+                .map(|col| {
+                    if col % unit == 0 {
+                        digit(col / unit)
+                    } else {
+                        ' '
+                    }
+                })
+                .collect();
+            write!(f, "{:margin$}{}", "", line.trim_end())?;
+            f.write_str(input::NEW_LINE)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number_width = digits(self.grid.height().saturating_sub(1));
+        if self.rulers {
+            self.write_column_rulers(f, number_width + 1)?;
+        }
+        for (row, cells) in self.grid.rows().enumerate() {
+            if self.rulers {
+                write!(f, "{row:>number_width$} ")?;
+            }
+            for (col, cell) in cells.iter().enumerate() {
+                match self.marks.get(&GridPosition::new(col, row)) {
+                    Some(mark) if self.colour => {
+                        write!(f, "\x1b[{}m{}\x1b[0m", mark.ansi, mark.glyph)?
+                    }
+                    Some(mark) => write!(f, "{}", mark.glyph)?,
+                    None => cell.fmt(f)?,
+                }
+            }
+            f.write_str(input::NEW_LINE)?;
+        }
+        Ok(())
+    }
+}
+
+fn direction(from: &GridPosition, to: &GridPosition) -> Option<Direction> {
+    let offset = (
+        to.col as isize - from.col as isize,
+        to.row as isize - from.row as isize,
+    );
+    Direction::ALL.into_iter().find(|d| d.offset() == offset)
+}
+
+fn arrow(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+        Direction::UpRight | Direction::DownLeft => '/',
+        Direction::DownRight | Direction::UpLeft => '\\',
+    }
+}
+
+/// How many digits `n` is written with
+fn digits(n: usize) -> usize {
+    n.checked_ilog10().map_or(1, |d| d as usize + 1)
+}
+
+/// The last digit of `n`
+fn digit(n: usize) -> char {
+    char::from_digit((n % 10) as u32, 10).expect("a single digit")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortest_path::{self, ManhattenDistanceTo, NonDiagonalNeighbours};
+
+    #[test]
+    fn test_rulers() {
+        let g = Grid::new(vec![vec!['.'; 12]; 11]);
+        let drawn = render(&g).rulers().to_string();
+        let lines: Vec<&str> = drawn.lines().collect();
+
+        assert_eq!("   0         1", lines[0]);
+        assert_eq!("   012345678901", lines[1]);
+        assert_eq!(" 0 ............", lines[2]);
+        assert_eq!("10 ............", lines[12]);
+    }
+
+    #[test]
+    fn test_layers_and_colour() {
+        let g: Grid<char> = "...\n...".parse().unwrap();
+        let corners = [GridPosition::new(0, 0), GridPosition::new(2, 1)];
+        let drawn = render(&g)
+            .highlight(corners, '#')
+            .highlight([GridPosition::new(2, 1)], 'o')
+            .steps([GridPosition::new(0, 1), GridPosition::new(2, 0)]);
+
+        assert_eq!("#..\n*.o\n", drawn.to_string());
+        assert_eq!(
+            "\x1b[31m#\x1b[0m..\n\x1b[1;33m*\x1b[0m.\x1b[32mo\x1b[0m\n",
+            drawn.colour(true).to_string()
+        );
+    }
+
+    #[test]
+    fn test_path() {
+        let g: Grid<char> = "S.\n.E".parse().unwrap();
+        let path = Path {
+            path: vec![(GridPosition::new(1, 1), 1), (GridPosition::new(1, 0), 1)],
+            total_cost: 2,
+        };
+        let start = GridPosition::new(0, 0);
+        assert_eq!(">v\n.E\n", render(&g).path(start, &path).to_string());
+
+        let g: Grid<usize> = "111\n191\n111".parse().unwrap();
+        let end = GridPosition::new(2, 2);
+        let path = shortest_path::astar(
+            &NonDiagonalNeighbours(&g),
+            &g,
+            &ManhattenDistanceTo(end),
+            start,
+            |p: &GridPosition| *p == end,
+        )
+        .unwrap();
+        assert_eq!(">>v\n19v\n111\n", render(&g).path(start, &path).to_string());
+    }
+}
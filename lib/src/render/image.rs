@@ -0,0 +1,329 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    cartesian::{Plane, Point},
+    grid::{Grid, SparseGrid},
+};
+
+/// A colour for a cell or pixel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(255, 0, 0);
+    pub const GREEN: Rgb = Rgb(0, 255, 0);
+    pub const BLUE: Rgb = Rgb(0, 0, 255);
+
+    /// Somewhere from `from` at 0 to `to` at 1, for colouring heights and
+    /// distances
+    pub fn blend(from: Rgb, to: Rgb, at: f64) -> Rgb {
+        let at = at.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * at).round() as u8;
+        Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+    }
+}
+
+/// The kinds of file an [`Image`] can be written as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Binary PPM, which most image viewers open
+    Ppm,
+    /// One rectangle per run of same coloured cells, for pictures too big
+    /// to make out in a terminal
+    Svg,
+}
+
+impl Format {
+    /// The format a file name ends in
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()? {
+            "ppm" => Some(Format::Ppm),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+/// A picture with a pixel per cell, made from a [`Grid`], [`SparseGrid`]
+/// or set of [`Point`]s and a colour for each cell.
+///
+/// ```
+/// use aoclib::{grid::Grid, render::{Image, Rgb}};
+///
+/// let g: Grid<char> = "#.\n.#".parse().unwrap();
+/// let image = Image::from_grid(&g, |c| if *c == '#' { Rgb::BLACK } else { Rgb::WHITE });
+/// let mut ppm = vec![];
+/// image.write_ppm(&mut ppm).unwrap();
+/// assert!(ppm.starts_with(b"P6\n2 2\n255\n"));
+/// assert_eq!(11 + 2 * 2 * 3, ppm.len());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// An image every pixel of which is `background`
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, mut colour: impl FnMut(&T) -> Rgb) -> Self {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.rows().flatten().map(&mut colour).collect(),
+        }
+    }
+
+    /// The cells of `plane` with `y` growing down, whichever way round its
+    /// corners are
+    pub fn from_plane(plane: &Plane, mut colour: impl FnMut(&Point) -> Rgb) -> Self {
+        let (a, b) = (&plane.top_left, &plane.bottom_right);
+        let (min_x, min_y) = (a.x.min(b.x), a.y.min(b.y));
+        let (width, height) = (plane.width() as usize, plane.height() as usize);
+        let pixels = (0..height as i64)
+            .flat_map(|y| (0..width as i64).map(move |x| Point::new(min_x + x, min_y + y)))
+            .map(|p| colour(&p))
+            .collect();
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// The area within [`SparseGrid::bounds`], empty when nothing is set
+    pub fn from_sparse<T>(grid: &SparseGrid<T>, mut colour: impl FnMut(&T) -> Rgb) -> Self {
+        match grid.bounds() {
+            Some((top_left, bottom_right)) => Image::from_plane(
+                &Plane {
+                    top_left,
+                    bottom_right,
+                },
+                |p| colour(grid.get(p)),
+            ),
+            None => Image::new(0, 0, Rgb::BLACK),
+        }
+    }
+
+    /// `on` for each of `points` and `off` around them, over the smallest
+    /// area holding them all
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Point>, on: Rgb, off: Rgb) -> Self {
+        let mut grid = SparseGrid::new(false);
+        for p in points {
+            grid.insert(p.clone(), true);
+        }
+        Image::from_sparse(&grid, |set| if *set { on } else { off })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The colour at column `x` and row `y`
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        if x >= self.width {
+            return None;
+        }
+        self.pixels.get(y * self.width + x).copied()
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, colour: Rgb) {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside an image {} wide and {} high",
+            self.width,
+            self.height
+        );
+        self.pixels[y * self.width + x] = colour;
+    }
+
+    pub fn write_ppm(&self, mut w: impl Write) -> io::Result<()> {
+        write!(w, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let bytes: Vec<u8> = self.pixels.iter().flat_map(|c| [c.0, c.1, c.2]).collect();
+        w.write_all(&bytes)
+    }
+
+    /// Each cell drawn as a square `scale` units across
+    pub fn write_svg(&self, mut w: impl Write, scale: usize) -> io::Result<()> {
+        writeln!(
+            w,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+            self.width * scale,
+            self.height * scale,
+            self.width,
+            self.height
+        )?;
+        for (y, row) in self.pixels.chunks(self.width.max(1)).enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                let Rgb(r, g, b) = run[0];
+                writeln!(
+                    w,
+                    r##"<rect x="{x}" y="{y}" width="{}" height="1" fill="#{r:02x}{g:02x}{b:02x}"/>"##,
+                    run.len()
+                )?;
+                x += run.len();
+            }
+        }
+        writeln!(w, "</svg>")
+    }
+
+    pub fn write(&self, w: impl Write, format: Format) -> io::Result<()> {
+        match format {
+            Format::Ppm => self.write_ppm(w),
+            Format::Svg => self.write_svg(w, 8),
+        }
+    }
+
+    /// Writes the image to `path` in the format its extension names
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = Format::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} doesn't end in .ppm or .svg", path.display()),
+            )
+        })?;
+        self.write(io::BufWriter::new(fs::File::create(path)?), format)
+    }
+}
+
+/// Numbered images in a directory, one per step of an animation, for
+/// flicking through to find the step that looks right
+///
+/// ```no_run
+/// use aoclib::render::{Format, Frames, Image, Rgb};
+///
+/// let mut frames = Frames::new("frames", Format::Ppm).unwrap();
+/// for step in 0..3 {
+///     let mut image = Image::new(3, 1, Rgb::BLACK);
+///     image.set(step, 0, Rgb::WHITE);
+///     frames.save(&image).unwrap();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    format: Format,
+    count: usize,
+}
+
+impl Frames {
+    /// Makes `dir` if it isn't there yet
+    pub fn new(dir: impl Into<PathBuf>, format: Format) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Frames {
+            dir,
+            format,
+            count: 0,
+        })
+    }
+
+    /// Saves `image` as the next frame, `frame-00000.ppm` then
+    /// `frame-00001.ppm` and so on, giving back where it went
+    pub fn save(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = self.dir.join(format!(
+            "frame-{:05}.{}",
+            self.count,
+            self.format.extension()
+        ));
+        image.save(&path)?;
+        self.count += 1;
+        Ok(path)
+    }
+
+    /// How many frames have been saved
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_images() {
+        let mut sparse = SparseGrid::new(false);
+        sparse.insert(Point::new(-1, 5), true);
+        sparse.insert(Point::new(1, 6), true);
+        let image = Image::from_sparse(&sparse, |on| if *on { Rgb::WHITE } else { Rgb::BLACK });
+
+        assert_eq!((3, 2), (image.width(), image.height()));
+        assert_eq!(Some(Rgb::WHITE), image.get(0, 0));
+        assert_eq!(Some(Rgb::BLACK), image.get(1, 0));
+        assert_eq!(Some(Rgb::WHITE), image.get(2, 1));
+        assert_eq!(None, image.get(3, 0));
+
+        let points = [Point::new(-1, 5), Point::new(1, 6)];
+        assert_eq!(image, Image::from_points(&points, Rgb::WHITE, Rgb::BLACK));
+        assert_eq!(Rgb(128, 128, 128), Rgb::blend(Rgb::BLACK, Rgb::WHITE, 0.5));
+    }
+
+    #[test]
+    fn test_svg() {
+        let g = Grid::new(vec![vec![1, 1, 2], vec![3, 3, 3]]);
+        let image = Image::from_grid(&g, |n| Rgb(*n, 0, 0));
+        let mut svg = vec![];
+        image.write_svg(&mut svg, 10).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        let lines: Vec<&str> = svg.lines().collect();
+
+        assert!(lines[0].contains(r#"width="30" height="20" viewBox="0 0 3 2""#));
+        assert_eq!(
+            vec![
+                r##"<rect x="0" y="0" width="2" height="1" fill="#010000"/>"##,
+                r##"<rect x="2" y="0" width="1" height="1" fill="#020000"/>"##,
+                r##"<rect x="0" y="1" width="3" height="1" fill="#030000"/>"##,
+                "</svg>",
+            ],
+            lines[1..]
+        );
+    }
+
+    #[test]
+    fn test_frames() {
+        let dir = std::env::temp_dir().join(format!("aoclib-frames-{}", std::process::id()));
+        let mut frames = Frames::new(&dir, Format::Svg).unwrap();
+        let image = Image::new(1, 1, Rgb::RED);
+
+        frames.save(&image).unwrap();
+        let second = frames.save(&image).unwrap();
+        assert_eq!(dir.join("frame-00001.svg"), second);
+        assert_eq!(2, frames.len());
+        assert!(fs::read_to_string(&second).unwrap().contains("#ff0000"));
+
+        assert!(image.save(dir.join("frame.png")).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -9,12 +9,26 @@ use std::{
 
 use hashbrown::HashMap;
 
-use crate::grid::{Grid, GridPosition};
+use crate::{
+    cartesian::Point,
+    grid::{Direction, Grid, GridPosition, Wrapping},
+};
 
 pub trait Neighbours<S> {
     fn neighbours(&self, state: &S) -> Vec<S>;
 }
 
+/// Lets a closure be used for one-off neighbours, eg: filtering
+/// [`Grid::neighbours4`] down to the cells that can be entered
+impl<S, F> Neighbours<S> for F
+where
+    F: Fn(&S) -> Vec<S>,
+{
+    fn neighbours(&self, state: &S) -> Vec<S> {
+        self(state)
+    }
+}
+
 pub trait Cost<S, C>
 where
     C: PartialOrd,
@@ -207,28 +221,37 @@ impl Heuristic<GridPosition, i64> for ManhattenDistanceTo {
     }
 }
 
+/// Every position sharing an edge with another on a grid, as right, up, left
+/// then down, which is the order searches have always broken ties in
 pub struct NonDiagonalNeighbours<'a, T>(pub &'a Grid<T>);
 
 impl<'a, T> Neighbours<GridPosition> for NonDiagonalNeighbours<'a, T> {
     fn neighbours(&self, state: &GridPosition) -> Vec<GridPosition> {
-        let mut result = Vec::new();
-
-        if state.right().col < self.0.width() {
-            result.push(state.right());
-        }
+        [
+            Direction::Right,
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+        ]
+        .into_iter()
+        .filter_map(|d| self.0.step(state, d))
+        .collect()
+    }
+}
 
-        if state.row as i32 - 1 >= 0 {
-            result.push(state.up());
-        }
+/// Every position touching another on a grid, diagonals included
+pub struct AllNeighbours<'a, T>(pub &'a Grid<T>);
 
-        if state.col as i32 - 1 >= 0 {
-            result.push(state.left());
-        }
+impl<'a, T> Neighbours<GridPosition> for AllNeighbours<'a, T> {
+    fn neighbours(&self, state: &GridPosition) -> Vec<GridPosition> {
+        self.0.neighbours8(state).map(|(p, _)| p).collect()
+    }
+}
 
-        if state.row + 1 < self.0.height() {
-            result.push(state.down());
-        }
-        result
+/// Every position sharing an edge with another, on a grid with no edges
+impl<T> Neighbours<Point> for Wrapping<&Grid<T>> {
+    fn neighbours(&self, state: &Point) -> Vec<Point> {
+        self.neighbours4(state).map(|(p, _)| p).collect()
     }
 }
 
@@ -247,7 +270,7 @@ mod tests {
 
     use crate::{
         grid::Grid,
-        shortest_path::{GridPosition, ManhattenDistanceTo, NonDiagonalNeighbours},
+        shortest_path::{AllNeighbours, GridPosition, ManhattenDistanceTo, NonDiagonalNeighbours},
         *,
     };
 
@@ -300,20 +323,10 @@ mod tests {
         .parse::<Grid<char>>()
         .unwrap();
 
-        let Grid { rows } = grid;
-        let grid = Grid {
-            rows: rows
-                .into_iter()
-                .map(|i| {
-                    i.into_iter()
-                        .map(|c| match c {
-                            '#' => 10000000,
-                            other => other.to_digit(10).unwrap() as usize,
-                        })
-                        .collect::<Vec<_>>()
-                })
-                .collect(),
-        };
+        let grid = grid.map(|c| match c {
+            '#' => 10000000,
+            other => other.to_digit(10).unwrap() as usize,
+        });
 
         let neighbours = NonDiagonalNeighbours(&grid);
 
@@ -354,5 +367,54 @@ mod tests {
         assert!(result.contains(&GridPosition::new(8, 2)));
         assert!(result.contains(&GridPosition::new(7, 1)));
         assert!(result.contains(&GridPosition::new(8, 0)));
+
+        assert_eq!(
+            vec![
+                GridPosition::new(5, 1),
+                GridPosition::new(4, 0),
+                GridPosition::new(3, 1),
+                GridPosition::new(4, 2),
+            ],
+            neighbours.neighbours(&GridPosition::new(4, 1))
+        );
+    }
+
+    #[test]
+    fn test_closure_neighbours() {
+        let grid = "\
+        ..#\n\
+        .#.\n\
+        ...\
+        "
+        .parse::<Grid<char>>()
+        .unwrap();
+        let costs = grid.map(|_| 1_usize);
+        let end = GridPosition::new(2, 1);
+
+        let open = |p: &GridPosition| -> Vec<GridPosition> {
+            grid.neighbours4(p)
+                .filter(|(_, c)| **c != '#')
+                .map(|(p, _)| p)
+                .collect()
+        };
+        let result = shortest_path::astar(
+            &open,
+            &costs,
+            &ManhattenDistanceTo(end),
+            GridPosition::new(0, 0),
+            |&pos: &_| end == pos,
+        )
+        .unwrap();
+        assert_eq!(5, result.total_cost);
+
+        let diagonal = shortest_path::astar(
+            &AllNeighbours(&costs),
+            &costs,
+            &ManhattenDistanceTo(end),
+            GridPosition::new(0, 0),
+            |&pos: &_| end == pos,
+        )
+        .unwrap();
+        assert_eq!(2, diagonal.total_cost);
     }
 }
@@ -0,0 +1,537 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    path::PathBuf,
+    process,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::Answers,
+    input,
+    timing::{self, Format, Record},
+};
+
+/// A single day's puzzle.
+///
+/// The puzzle text is parsed once by `parse` and the result shared by both parts.
+/// Days whose parts work straight from the puzzle text can use the
+/// [`solution!`](crate::solution!) macro rather than implementing this by hand.
+///
+/// eg:
+/// ```
+/// use aoclib::{
+///     Answer,
+///     solution::{Part, Runnable, Solution},
+/// };
+///
+/// struct Day1;
+///
+/// impl Solution for Day1 {
+///     const YEAR: u16 = 2024;
+///     const DAY: u8 = 1;
+///
+///     type Input<'a> = Vec<i64>;
+///
+///     fn parse(txt: &str) -> Self::Input<'_> {
+///         txt.lines().map(|l| l.parse().unwrap()).collect()
+///     }
+///
+///     fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+///         input.iter().sum::<i64>()
+///     }
+///
+///     fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+///         input.iter().product::<i64>()
+///     }
+/// }
+///
+/// let result = Day1.run("1\n2\n3", &Part::ALL);
+/// assert_eq!("6", result.parts[0].answer);
+/// assert_eq!("6", result.parts[1].answer);
+/// ```
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+
+    fn parse(txt: &str) -> Self::Input<'_>;
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer>;
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer>;
+}
+
+/// Implements [`Solution`] for a day whose `part1` and `part2`
+/// functions take the raw puzzle text.
+///
+/// ```ignore
+/// aoclib::solution!(Day17, 2024, 17);
+/// ```
+#[macro_export]
+macro_rules! solution {
+    ($name:ident, $year:literal, $day:literal) => {
+        pub struct $name;
+
+        impl $crate::solution::Solution for $name {
+            const YEAR: u16 = $year;
+            const DAY: u8 = $day;
+
+            type Input<'a> = &'a str;
+
+            fn parse(txt: &str) -> Self::Input<'_> {
+                txt
+            }
+
+            fn part1(input: &Self::Input<'_>) -> impl Into<$crate::Answer> {
+                part1(input)
+            }
+
+            fn part2(input: &Self::Input<'_>) -> impl Into<$crate::Answer> {
+                part2(input)
+            }
+        }
+    };
+}
+
+/// The answer for a part with nothing to solve, such as the second star on day 25.
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("-")
+    }
+}
+
+/// What a part returns, so every day's results can be printed, compared
+/// and stored the same way whatever type the part worked in.
+///
+/// ```
+/// use aoclib::Answer;
+///
+/// assert_eq!(Answer::Integer(42), Answer::from(42_usize));
+/// assert_eq!("3,6,7", Answer::from("3,6,7".to_owned()));
+/// assert_eq!("#.\n.#", Answer::from([['#', '.'], ['.', '#']]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// A picture to be read, such as letters drawn on a CRT, one string per row
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Whether the answer needs more than one line to print
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Integer(_) => false,
+            Answer::Text(text) => text.contains('\n'),
+            Answer::Grid(rows) => rows.len() > 1,
+        }
+    }
+
+    /// The answer as a TOML value, the way `answers.toml` keeps it: integers
+    /// bare and everything else quoted, so [`Answer`]'s `FromStr` gets the
+    /// same variant back
+    ///
+    /// ```
+    /// use aoclib::Answer;
+    ///
+    /// assert_eq!("-7", Answer::Integer(-7).to_toml());
+    /// assert_eq!("\"0123\"", Answer::from("0123".to_owned()).to_toml());
+    /// ```
+    pub fn to_toml(&self) -> String {
+        match self {
+            Answer::Integer(n) => n.to_string(),
+            answer => {
+                let escaped = answer
+                    .to_string()
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n")
+                    .replace('\t', "\\t");
+                format!("\"{escaped}\"")
+            }
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => n.fmt(f),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+/// Reads an answer written as a TOML value: a quoted string is text, or a
+/// grid if it spans several lines, even when it's all digits. Only a bare
+/// integer is an integer.
+impl FromStr for Answer {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(quoted) = s.strip_prefix('"').and_then(|v| v.strip_suffix('"')) else {
+            return match s.parse::<i128>() {
+                // leading zeros or a plus sign would be lost printing it back
+                Ok(n) if n.to_string() == s => Ok(Answer::Integer(n)),
+                _ => Err("expected an integer or string"),
+            };
+        };
+
+        let mut text = String::new();
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some('n') => text.push('\n'),
+                    Some('t') => text.push('\t'),
+                    Some('"') => text.push('"'),
+                    Some('\\') => text.push('\\'),
+                    _ => return Err("unknown escape in string"),
+                },
+                '"' => return Err("unescaped quote in string"),
+                c => text.push(c),
+            }
+        }
+        Ok(if text.contains('\n') {
+            Answer::Grid(text.lines().map(|l| l.to_owned()).collect())
+        } else {
+            Answer::Text(text)
+        })
+    }
+}
+
+macro_rules! integer_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+integer_answer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+impl<const W: usize, const H: usize> From<[[char; W]; H]> for Answer {
+    fn from(rows: [[char; W]; H]) -> Self {
+        Answer::Grid(rows.iter().map(|row| row.iter().collect()).collect())
+    }
+}
+
+impl From<NoAnswer> for Answer {
+    fn from(_: NoAnswer) -> Self {
+        Answer::Text(NoAnswer.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Text(text) => text == other,
+            _ => {
+                let printed = self.to_string();
+                printed == other
+            }
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("bad part '{other}', expected 1 or 2")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// The outcome of running one day against its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+impl Run {
+    /// Total time spent parsing and solving
+    pub fn elapsed(&self) -> Duration {
+        self.parse + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+    }
+}
+
+/// Object safe view of a [`Solution`] so days from every year
+/// can be registered together and run by number.
+///
+/// Implemented for every [`Solution`].
+pub trait Runnable: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn run(&self, txt: &str, parts: &[Part]) -> Run;
+}
+
+impl<S> Runnable for S
+where
+    S: Solution + Sync,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, txt: &str, parts: &[Part]) -> Run {
+        run::<S>(txt, parts)
+    }
+}
+
+pub(crate) fn run<S: Solution>(txt: &str, parts: &[Part]) -> Run {
+    let now = Instant::now();
+    let input = S::parse(txt);
+    let parse = now.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let now = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&input).into(),
+                Part::Two => S::part2(&input).into(),
+            };
+            PartResult {
+                part,
+                answer,
+                elapsed: now.elapsed(),
+            }
+        })
+        .collect();
+
+    Run {
+        year: S::YEAR,
+        day: S::DAY,
+        parse,
+        parts,
+    }
+}
+
+/// The body of every day's `main`: loads the puzzle input, solves both parts
+/// and prints them along with the time taken.
+///
+/// The input is read from `--input <path>` or [`input::INPUT_VAR`] when given,
+/// otherwise from [`input::default_path`], in which case each answer is also
+/// checked against the year's [`Answers`].
+/// `--format json|csv`, or [`timing::OUTPUT_VAR`], prints [`Record`]s instead of text.
+pub fn main<S: Solution>() {
+    let usage = format!(
+        "usage: d{} [--input <path>] [--format text|json|csv]",
+        S::DAY
+    );
+
+    let mut path = None;
+    let mut format = Format::from_env().unwrap_or_else(|e| exit(e, 2));
+    let args: Vec<String> = env::args().skip(1).collect();
+    for flag in args.chunks(2) {
+        match flag {
+            [flag, p] if flag == "--input" => path = Some(p.clone()),
+            [flag, f] if flag == "--format" => {
+                format = Format::parse(f).unwrap_or_else(|e| exit(e, 2))
+            }
+            _ => exit(&usage, 2),
+        }
+    }
+
+    // answers only apply to the real puzzle input
+    let (txt, answers) = match path.map(PathBuf::from).or_else(input::override_path) {
+        None => (input::load(S::YEAR, S::DAY), Some(Answers::load(S::YEAR))),
+        Some(path) => (input::read(path), None),
+    };
+    let txt = txt.unwrap_or_else(|e| exit(e, 1));
+    let answers = answers.and_then(|a| a.map_err(|e| eprintln!("{e}")).ok());
+
+    let result = run::<S>(&txt, &Part::ALL);
+    if let Some(format) = format {
+        timing::print_records(format, &Record::from_run(&result, answers.as_ref()));
+        return;
+    }
+
+    for p in &result.parts {
+        let verdict = answers
+            .as_ref()
+            .map(|a| format!(" ({})", a.check(S::DAY, p.part, &p.answer)))
+            .unwrap_or_default();
+        if p.answer.is_multiline() {
+            println!("{}:{verdict}\n{}", p.part, p.answer);
+        } else {
+            println!("{}: {}{verdict}", p.part, p.answer);
+        }
+    }
+    println!("{}", timing::format_elapsed_time(result.elapsed()));
+}
+
+fn exit<T>(message: impl Display, code: i32) -> T {
+    eprintln!("{message}");
+    process::exit(code)
+}
+
+/// The message a caught panic was raised with
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part1(txt: &str) -> usize {
+        txt.lines().count()
+    }
+
+    fn part2(txt: &str) -> String {
+        txt.lines().rev().collect::<Vec<_>>().join(",")
+    }
+
+    crate::solution!(Example, 2024, 1);
+
+    #[test]
+    fn test_runnable() {
+        let runnable: &dyn Runnable = &Example;
+        assert_eq!(2024, runnable.year());
+        assert_eq!(1, runnable.day());
+
+        let result = runnable.run("a\nb\nc", &Part::ALL);
+        let answers: Vec<_> = result
+            .parts
+            .iter()
+            .map(|p| (p.part, p.answer.clone()))
+            .collect();
+        assert_eq!(
+            vec![
+                (Part::One, Answer::Integer(3)),
+                (Part::Two, Answer::Text("c,b,a".to_owned()))
+            ],
+            answers
+        );
+    }
+
+    #[test]
+    fn test_single_part() {
+        let result = Example.run("a\nb", &[Part::Two]);
+        assert_eq!(1, result.parts.len());
+        assert_eq!("b,a", result.parts[0].answer);
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::Integer(-7), Answer::from(-7_i64));
+        assert_eq!(Answer::Integer(u64::MAX as i128), Answer::from(u64::MAX));
+        assert_eq!("-", Answer::from(NoAnswer));
+
+        let crt = Answer::from([['#'; 3], ['.'; 3]]);
+        assert!(crt.is_multiline());
+        assert_eq!("###\n...", crt.to_string());
+
+        let answers = [
+            Answer::Integer(12),
+            Answer::from("1,2"),
+            Answer::from("0123"),
+            Answer::from("12"),
+            Answer::from("a \"quoted\"\\answer"),
+            crt,
+        ];
+        for answer in answers {
+            assert_eq!(Ok(answer.clone()), answer.to_toml().parse());
+        }
+    }
+
+    #[test]
+    fn test_parse_answer() {
+        assert_eq!(Ok(Answer::Integer(-23)), "-23".parse());
+        assert_eq!(Ok(Answer::Text("0123".to_owned())), "\"0123\"".parse());
+        assert!("0123".parse::<Answer>().is_err());
+        assert!("+1".parse::<Answer>().is_err());
+        assert!("abc".parse::<Answer>().is_err());
+        assert!("\"a\\qb\"".parse::<Answer>().is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(Ok(Part::One), "1".parse());
+        assert_eq!(Ok(Part::Two), "2".parse());
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -1,4 +1,11 @@
-use std::time::Duration;
+use std::{env, time::Duration};
+
+use serde::Serialize;
+
+use crate::{answers::Answers, solution::Run};
+
+/// Set to `json` or `csv` to have days print [`Record`]s rather than text
+pub const OUTPUT_VAR: &str = "AOC_OUTPUT";
 
 pub fn format_elapsed_time(duration: Duration) -> String {
     let secs = duration.as_secs_f64();
@@ -23,9 +30,135 @@ pub fn format_elapsed_time(duration: Duration) -> String {
     }
 }
 
+/// How results are printed when plain text won't do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One JSON object per [`Record`] per line
+    JsonLines,
+    /// A header line then one row per [`Record`]
+    Csv,
+}
+
+impl Format {
+    /// Parses a `--format` value, `None` meaning plain text
+    pub fn parse(s: &str) -> Result<Option<Self>, String> {
+        match s {
+            "text" => Ok(None),
+            "json" | "jsonl" => Ok(Some(Format::JsonLines)),
+            "csv" => Ok(Some(Format::Csv)),
+            other => Err(format!("bad format '{other}', expected text, json or csv")),
+        }
+    }
+
+    /// The format chosen by [`OUTPUT_VAR`], plain text when unset
+    pub fn from_env() -> Result<Option<Self>, String> {
+        match env::var(OUTPUT_VAR) {
+            Ok(format) => Format::parse(&format),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+/// One timed stage of a day, for scripts and dashboards
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    /// `parse`, `part1` or `part2`, or `day` when the day as a whole failed
+    pub part: String,
+    pub answer: Option<String>,
+    pub duration_ns: u64,
+    /// `correct`, `wrong` or `unknown` when checked against the accepted answers,
+    /// `unchecked` otherwise, or why the day failed
+    pub status: String,
+}
+
+impl Record {
+    pub const CSV_HEADER: &str = "year,day,part,answer,duration_ns,status";
+
+    /// A record for parsing then one for each part
+    pub fn from_run(run: &Run, answers: Option<&Answers>) -> Vec<Self> {
+        let parse = Record {
+            year: run.year,
+            day: run.day,
+            part: "parse".to_owned(),
+            answer: None,
+            duration_ns: run.parse.as_nanos() as u64,
+            status: "ok".to_owned(),
+        };
+        let parts = run.parts.iter().map(|p| Record {
+            year: run.year,
+            day: run.day,
+            part: p.part.to_string(),
+            answer: Some(p.answer.to_string()),
+            duration_ns: p.elapsed.as_nanos() as u64,
+            status: answers.map_or("unchecked".to_owned(), |a| {
+                a.check(run.day, p.part, &p.answer).status().to_owned()
+            }),
+        });
+        [parse].into_iter().chain(parts).collect()
+    }
+
+    /// A record for a day that didn't produce any answers
+    pub fn failed(year: u16, day: u8, status: impl Into<String>) -> Self {
+        Record {
+            year,
+            day,
+            part: "day".to_owned(),
+            answer: None,
+            duration_ns: 0,
+            status: status.into(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records serialize")
+    }
+
+    /// A CSV row to go under [`Record::CSV_HEADER`]
+    pub fn to_csv(&self) -> String {
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.clone(),
+            self.answer.clone().unwrap_or_default(),
+            self.duration_ns.to_string(),
+            self.status.clone(),
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Writes `records` to stdout, which holds nothing else: days print anything
+/// they want to show while solving to stderr.
+pub fn print_records(format: Format, records: &[Record]) {
+    match format {
+        Format::JsonLines => records.iter().for_each(|r| println!("{}", r.to_json())),
+        Format::Csv => {
+            println!("{}", Record::CSV_HEADER);
+            records.iter().for_each(|r| println!("{}", r.to_csv()));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::timing::*;
+    use crate::{
+        Answer,
+        solution::{Part, PartResult},
+        timing::*,
+    };
 
     #[test]
     fn test_format() {
@@ -51,4 +184,50 @@ mod tests {
             format_elapsed_time(Duration::from_secs_f64(0.00000034))
         );
     }
+
+    #[test]
+    fn test_records() {
+        let run = Run {
+            year: 2022,
+            day: 10,
+            parse: Duration::from_nanos(5),
+            parts: vec![
+                PartResult {
+                    part: Part::One,
+                    answer: Answer::Integer(13140),
+                    elapsed: Duration::from_nanos(7),
+                },
+                PartResult {
+                    part: Part::Two,
+                    answer: Answer::Grid(vec!["##..".to_owned(), "\"#\",".to_owned()]),
+                    elapsed: Duration::from_nanos(9),
+                },
+            ],
+        };
+        let answers: Answers = "[d10]\npart1 = 13140".parse().unwrap();
+        let records = Record::from_run(&run, Some(&answers));
+
+        let statuses: Vec<_> = records.iter().map(|r| r.status.as_str()).collect();
+        assert_eq!(vec!["ok", "correct", "unknown"], statuses);
+        assert_eq!(
+            r#"{"year":2022,"day":10,"part":"part1","answer":"13140","duration_ns":7,"status":"correct"}"#,
+            records[1].to_json()
+        );
+        assert_eq!("2022,10,parse,,5,ok", records[0].to_csv());
+        assert_eq!(
+            "2022,10,part2,\"##..\n\"\"#\"\",\",9,unknown",
+            records[2].to_csv()
+        );
+
+        let unchecked = Record::from_run(&run, None);
+        assert_eq!("unchecked", unchecked[1].status);
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(Ok(None), Format::parse("text"));
+        assert_eq!(Ok(Some(Format::JsonLines)), Format::parse("json"));
+        assert_eq!(Ok(Some(Format::Csv)), Format::parse("csv"));
+        assert!(Format::parse("xml").is_err());
+    }
 }